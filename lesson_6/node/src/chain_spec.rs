@@ -3,7 +3,7 @@ use hex_literal::hex;
 use node_template_runtime::{
 	AccountId, BabeConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
 	SystemConfig, WASM_BINARY, BABE_GENESIS_EPOCH_CONFIG, SessionConfig, StakingConfig, SessionKeys,
-	constants::currency::*, StakerStatus, MaxNominations, ImOnlineConfig,
+	constants::currency::*, StakerStatus, MaxNominations, ImOnlineConfig, PriceModuleConfig,
 };
 use sc_service::ChainType;
 use sp_consensus_babe::AuthorityId as BabeId;
@@ -344,6 +344,10 @@ fn testnet_genesis(
 			..Default::default()
 		},
 		im_online: ImOnlineConfig { keys: vec![] },
+		price_module: PriceModuleConfig {
+			// 验证人的控制账户可以提交快递价格
+			authorities: initial_authorities.iter().map(|x| x.1.clone()).collect(),
+		},
 		sudo: SudoConfig {
 			// Assign network admin rights.
 			key: Some(root_key),
@@ -0,0 +1,19 @@
+//! Runtime API definition for the kitties pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_kitties::genome::Genome;
+use scale_info::prelude::string::String;
+
+sp_api::decl_runtime_apis! {
+	pub trait KittiesApi<KittyId> where
+		KittyId: Codec,
+	{
+		/// The traits expressed by the DNA of `kitty_id`, if it exists.
+		fn traits_of(kitty_id: KittyId) -> Option<Genome>;
+
+		/// The name of `kitty_id` without its zero padding, if it exists and is valid UTF-8.
+		fn name_of(kitty_id: KittyId) -> Option<String>;
+	}
+}
@@ -0,0 +1,592 @@
+//! Benchmarking setup for pallet-kitties
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	traits::{tokens::fungibles, Currency, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{AccountIdConversion, Bounded, Hash};
+use sp_std::vec::Vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn fund_account<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+// 名字各不相同，打开 UniqueNames 时也可以使用
+fn name(index: u32) -> [u8; 8] {
+	let mut name = *b"kitty000";
+	name[5] += (index / 100 % 10) as u8;
+	name[6] += (index / 10 % 10) as u8;
+	name[7] += (index % 10) as u8;
+	name
+}
+
+// 让账户已经拥有 MaxKittiesOwned - 1 个 kitty，索引的读写最重
+fn fill_owned_kitties<T: Config>(who: &T::AccountId) {
+	let max = T::MaxKittiesOwned::get().saturating_sub(1);
+	let owned = (0..max).map(|i| T::KittyId::max_value() - i.into()).collect::<Vec<_>>();
+	OwnedKitties::<T>::insert(who, BoundedVec::truncate_from(owned));
+}
+
+fn create_kitty<T: Config>(owner: &T::AccountId, index: u32) -> T::KittyId {
+	let kitty_id = Pallet::<T>::next_kitty_id();
+	assert!(Pallet::<T>::create(RawOrigin::Signed(owner.clone()).into(), name(index)).is_ok());
+	kitty_id
+}
+
+// 让 `end` 区块结束的拍卖只差一个就满了
+fn fill_auctions_ending_at<T: Config>(end: T::BlockNumber) {
+	let max = T::MaxAuctionsPerBlock::get().saturating_sub(1);
+	let ending = (0..max).map(|i| T::KittyId::max_value() - i.into()).collect::<Vec<_>>();
+	AuctionsEndingAt::<T>::insert(end, BoundedVec::truncate_from(ending));
+}
+
+// 让 `now` 之前揭示窗口内的区块都还没有随机数，再加一个刚过期的区块，队列已满
+fn fill_unseeded_breed_blocks<T: Config>(now: T::BlockNumber) {
+	let window = T::BreedRevealWindow::get();
+	let blocks = (1..=window).map(|i| now - i.into()).collect::<Vec<_>>();
+	UnseededBreedBlocks::<T>::put(BoundedVec::truncate_from(blocks));
+}
+
+// `who` 在区块 1 提交了引用这两个 kitty 的繁殖承诺，揭示窗口过后承诺作废，需要清除
+fn commit_in_first_block<T: Config>(
+	who: &T::AccountId,
+	kitty_id_1: T::KittyId,
+	kitty_id_2: T::KittyId,
+) {
+	let commitment = BreedCommitment {
+		kitty_id_1,
+		kitty_id_2,
+		commitment: T::Hashing::hash_of(&[7u8; 32]),
+		committed_at: 1u32.into(),
+	};
+	BreedCommitments::<T>::insert(who, commitment);
+	CommittedKitties::<T>::insert(kitty_id_1, who);
+	CommittedKitties::<T>::insert(kitty_id_2, who);
+}
+
+// 拆分的 kitty 的份额数量，远多于投票账户数，少数投票账户达不到买断的门槛
+const SHARES: u32 = 1_000;
+
+fn give_shares<T: Config>(
+	kitty_id: T::KittyId,
+	from: &T::AccountId,
+	to: &T::AccountId,
+	shares: u32,
+) {
+	let result = <T::ShareAssets as fungibles::Transfer<T::AccountId>>::transfer(
+		kitty_id,
+		from,
+		to,
+		shares.into(),
+		false,
+	);
+	assert!(result.is_ok());
+}
+
+// 拆分 `depositor` 的 kitty，`buyer` 出价买断，`voters` 个账户各用一份份额投票
+fn buyout_with_votes<T: Config>(
+	depositor: &T::AccountId,
+	buyer: &T::AccountId,
+	voters: u32,
+) -> T::KittyId {
+	fund_account::<T>(depositor);
+	let kitty_id = create_kitty::<T>(depositor, 0);
+	let origin = RawOrigin::Signed(depositor.clone());
+	assert!(Pallet::<T>::fractionalize(origin.into(), kitty_id, SHARES.into()).is_ok());
+
+	fund_account::<T>(buyer);
+	let price = T::KittyPrice::get();
+	let origin = RawOrigin::Signed(buyer.clone());
+	assert!(Pallet::<T>::offer_buyout(origin.into(), kitty_id, price).is_ok());
+
+	for i in 0..voters {
+		let voter: T::AccountId = account("voter", i, 0);
+		fund_account::<T>(&voter);
+		give_shares::<T>(kitty_id, depositor, &voter, 1);
+		assert!(Pallet::<T>::vote_buyout(RawOrigin::Signed(voter).into(), kitty_id).is_ok());
+	}
+	kitty_id
+}
+
+benchmarks! {
+	create {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fill_owned_kitties::<T>(&caller);
+		let kitty_id = Pallet::<T>::next_kitty_id();
+	}: _(RawOrigin::Signed(caller.clone()), name(0))
+	verify {
+		assert_eq!(Pallet::<T>::kitty_owner(kitty_id), Some(caller));
+	}
+
+	breed {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id_1 = create_kitty::<T>(&caller, 0);
+		let kitty_id_2 = create_kitty::<T>(&caller, 1);
+		let kitty_id = Pallet::<T>::next_kitty_id();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id_1, kitty_id_2, name(2))
+	verify {
+		assert_eq!(Pallet::<T>::kitty_owner(kitty_id), Some(caller));
+		assert_eq!(Pallet::<T>::kitty_parents(kitty_id), Some((kitty_id_1, kitty_id_2)));
+	}
+
+	transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		fund_account::<T>(&recipient);
+		fill_owned_kitties::<T>(&recipient);
+	}: _(RawOrigin::Signed(caller.clone()), recipient.clone(), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::KittyTransferred { who: caller, recipient, kitty_id }.into());
+	}
+
+	sale {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let price = T::KittyPrice::get();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_last_event::<T>(Event::KittyOnSale { who: caller, kitty_id, price }.into());
+	}
+
+	buy {
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund_account::<T>(&seller);
+		let kitty_id = create_kitty::<T>(&seller, 0);
+		let price = T::KittyPrice::get();
+		assert!(Pallet::<T>::sale(RawOrigin::Signed(seller.clone()).into(), kitty_id, price).is_ok());
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fill_owned_kitties::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_last_event::<T>(Event::KittyBought { who: caller, seller, kitty_id, price }.into());
+	}
+
+	update_price {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(Pallet::<T>::sale(origin.into(), kitty_id, T::KittyPrice::get()).is_ok());
+		let price = T::KittyPrice::get() * 2u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_last_event::<T>(Event::KittyPriceUpdated { who: caller, kitty_id, price }.into());
+	}
+
+	cancel_sale {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(Pallet::<T>::sale(origin.into(), kitty_id, T::KittyPrice::get()).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::KittySaleCancelled { who: caller, kitty_id }.into());
+	}
+
+	create_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let kind = AuctionKind::English { reserve: T::KittyPrice::get() };
+		let duration = T::MaxAuctionDuration::get();
+		let end = frame_system::Pallet::<T>::block_number() + duration;
+		fill_auctions_ending_at::<T>(end);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, kind, duration)
+	verify {
+		assert_last_event::<T>(Event::AuctionCreated { who: caller, kitty_id, kind, end }.into());
+	}
+
+	bid {
+		let seller: T::AccountId = account("seller", 0, 0);
+		fund_account::<T>(&seller);
+		let kitty_id = create_kitty::<T>(&seller, 0);
+		let price = T::KittyPrice::get();
+		let kind = AuctionKind::Dutch { start_price: price, floor_price: price };
+		let duration = T::MaxAuctionDuration::get();
+		fill_auctions_ending_at::<T>(frame_system::Pallet::<T>::block_number() + duration);
+		let origin = RawOrigin::Signed(seller.clone());
+		assert!(Pallet::<T>::create_auction(origin.into(), kitty_id, kind, duration).is_ok());
+		// 荷兰式拍卖的出价立即结算，比英式拍卖的出价更重
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fill_owned_kitties::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_last_event::<T>(
+			Event::AuctionSettled { seller, winner: caller, kitty_id, price }.into(),
+		);
+	}
+
+	settle_auctions {
+		let a in 0 .. T::MaxAuctionsPerBlock::get();
+		let duration: T::BlockNumber = 1u32.into();
+		let end = frame_system::Pallet::<T>::block_number() + duration;
+		let price = T::KittyPrice::get();
+		let kind = AuctionKind::English { reserve: price };
+		// 每个拍卖都有出价，结束时转移 kitty 并向卖家付款
+		let mut sold = Vec::new();
+		for i in 0 .. a {
+			let seller: T::AccountId = account("seller", i, 0);
+			fund_account::<T>(&seller);
+			let kitty_id = create_kitty::<T>(&seller, i);
+			let origin = RawOrigin::Signed(seller);
+			assert!(Pallet::<T>::create_auction(origin.into(), kitty_id, kind, duration).is_ok());
+			let bidder: T::AccountId = account("bidder", i, 0);
+			fund_account::<T>(&bidder);
+			let origin = RawOrigin::Signed(bidder.clone());
+			assert!(Pallet::<T>::bid(origin.into(), kitty_id, price).is_ok());
+			sold.push((kitty_id, bidder));
+		}
+	}: {
+		Pallet::<T>::on_initialize(end);
+	}
+	verify {
+		for (kitty_id, bidder) in sold {
+			assert_eq!(Pallet::<T>::kitty_owner(kitty_id), Some(bidder));
+		}
+	}
+
+	burn {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let parent_1 = create_kitty::<T>(&caller, 0);
+		let parent_2 = create_kitty::<T>(&caller, 1);
+		let kitty_id = Pallet::<T>::next_kitty_id();
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(Pallet::<T>::breed(origin.into(), parent_1, parent_2, name(2)).is_ok());
+		// 父母先被销毁，销毁最后一个子代时三个 id 都可以重用
+		for parent_id in [parent_1, parent_2] {
+			assert!(Pallet::<T>::burn(RawOrigin::Signed(caller.clone()).into(), parent_id).is_ok());
+		}
+		// 还要清除引用它的作废承诺
+		let committer: T::AccountId = account("committer", 0, 0);
+		commit_in_first_block::<T>(&committer, kitty_id, T::KittyId::max_value());
+		let now: T::BlockNumber = T::BreedRevealWindow::get().saturating_add(2).into();
+		frame_system::Pallet::<T>::set_block_number(now);
+		let deposit = T::KittyPrice::get();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::KittyBurned { who: caller, kitty_id, deposit }.into());
+	}
+
+	sweep_pallet_account {
+		let pallet_account: T::AccountId = T::PalletId::get().into_account_truncating();
+		fund_account::<T>(&pallet_account);
+		let dest: T::AccountId = account("dest", 0, 0);
+	}: _(RawOrigin::Root, dest)
+	verify {
+		assert_eq!(T::Currency::free_balance(&pallet_account), T::Currency::minimum_balance());
+	}
+
+	offer_siring {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let fee = T::KittyPrice::get();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, fee)
+	verify {
+		assert_last_event::<T>(Event::SiringOffered { who: caller, kitty_id, fee }.into());
+	}
+
+	cancel_siring {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let fee = T::KittyPrice::get();
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(Pallet::<T>::offer_siring(origin.into(), kitty_id, fee).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::SiringOfferCancelled { who: caller, kitty_id }.into());
+	}
+
+	breed_with_sire {
+		let sire_owner: T::AccountId = account("sire_owner", 0, 0);
+		fund_account::<T>(&sire_owner);
+		let sire_id = create_kitty::<T>(&sire_owner, 0);
+		let fee = T::KittyPrice::get();
+		let origin = RawOrigin::Signed(sire_owner);
+		assert!(Pallet::<T>::offer_siring(origin.into(), sire_id, fee).is_ok());
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let my_kitty = create_kitty::<T>(&caller, 1);
+		let kitty_id = Pallet::<T>::next_kitty_id();
+	}: _(RawOrigin::Signed(caller.clone()), my_kitty, sire_id, fee, name(2))
+	verify {
+		assert_eq!(Pallet::<T>::kitty_owner(kitty_id), Some(caller));
+		assert_eq!(Pallet::<T>::kitty_parents(kitty_id), Some((my_kitty, sire_id)));
+	}
+
+	commit_breed {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id_1 = create_kitty::<T>(&caller, 0);
+		let kitty_id_2 = create_kitty::<T>(&caller, 1);
+		// 父母还被别的账户作废的承诺引用，提交时要先清除
+		let committer: T::AccountId = account("committer", 0, 0);
+		commit_in_first_block::<T>(&committer, kitty_id_1, kitty_id_2);
+		let now: T::BlockNumber =
+			T::BreedRevealWindow::get().saturating_mul(2).saturating_add(2).into();
+		frame_system::Pallet::<T>::set_block_number(now);
+		fill_unseeded_breed_blocks::<T>(now);
+		let commitment = T::Hashing::hash_of(&[7u8; 32]);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id_1, kitty_id_2, commitment)
+	verify {
+		assert_last_event::<T>(
+			Event::BreedCommitted { who: caller, kitty_id_1, kitty_id_2 }.into(),
+		);
+	}
+
+	reveal_breed {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id_1 = create_kitty::<T>(&caller, 0);
+		let kitty_id_2 = create_kitty::<T>(&caller, 1);
+		let secret = [7u8; 32];
+		let commitment = T::Hashing::hash_of(&secret);
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(
+			Pallet::<T>::commit_breed(origin.into(), kitty_id_1, kitty_id_2, commitment).is_ok()
+		);
+		// 基准测试中随机数来源不一定会产生新的随机数，直接记录提交区块的随机数
+		let committed_at = frame_system::Pallet::<T>::block_number();
+		BreedSeeds::<T>::insert(committed_at, T::Hash::default());
+		let kitty_id = Pallet::<T>::next_kitty_id();
+	}: _(RawOrigin::Signed(caller.clone()), secret, name(2))
+	verify {
+		assert_eq!(Pallet::<T>::kitty_owner(kitty_id), Some(caller));
+		assert_eq!(Pallet::<T>::kitty_parents(kitty_id), Some((kitty_id_1, kitty_id_2)));
+	}
+
+	cancel_breed_commitment {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id_1 = create_kitty::<T>(&caller, 0);
+		let kitty_id_2 = create_kitty::<T>(&caller, 1);
+		let commitment = T::Hashing::hash_of(&[7u8; 32]);
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(
+			Pallet::<T>::commit_breed(origin.into(), kitty_id_1, kitty_id_2, commitment).is_ok()
+		);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_last_event::<T>(Event::BreedCommitmentCancelled { who: caller }.into());
+	}
+
+	approve {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund_account::<T>(&owner);
+		let kitty_id = create_kitty::<T>(&owner, 0);
+		// 操作员授权需要多读一次存储
+		let caller: T::AccountId = whitelisted_caller();
+		let origin = RawOrigin::Signed(owner.clone());
+		assert!(Pallet::<T>::set_approval_for_all(origin.into(), caller.clone(), true).is_ok());
+		let delegate: T::AccountId = account("delegate", 0, 0);
+	}: _(RawOrigin::Signed(caller), kitty_id, Some(delegate.clone()))
+	verify {
+		assert_last_event::<T>(
+			Event::Approval { owner, approved: Some(delegate), kitty_id }.into(),
+		);
+	}
+
+	set_approval_for_all {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone(), true)
+	verify {
+		assert_last_event::<T>(
+			Event::ApprovalForAll { owner: caller, operator, approved: true }.into(),
+		);
+	}
+
+	transfer_from {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund_account::<T>(&owner);
+		let kitty_id = create_kitty::<T>(&owner, 0);
+		// 调用者不是被授权的账户而是操作员，两种授权都要读取
+		let caller: T::AccountId = whitelisted_caller();
+		let origin = RawOrigin::Signed(owner.clone());
+		assert!(Pallet::<T>::set_approval_for_all(origin.into(), caller.clone(), true).is_ok());
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		fund_account::<T>(&recipient);
+		fill_owned_kitties::<T>(&recipient);
+	}: _(RawOrigin::Signed(caller), owner.clone(), recipient.clone(), kitty_id)
+	verify {
+		assert_last_event::<T>(
+			Event::KittyTransferred { who: owner, recipient, kitty_id }.into(),
+		);
+	}
+
+	rename {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let new_name = name(1);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, new_name)
+	verify {
+		assert_last_event::<T>(
+			Event::KittyRenamed { who: caller, kitty_id, name: new_name }.into(),
+		);
+	}
+
+	lend {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let borrower: T::AccountId = account("borrower", 0, 0);
+		let until = frame_system::Pallet::<T>::block_number() + T::MaxLoanDuration::get();
+		let fee = T::KittyPrice::get();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, borrower.clone(), until, fee)
+	verify {
+		assert_last_event::<T>(
+			Event::LoanOffered { who: caller, borrower, kitty_id, until, fee }.into(),
+		);
+	}
+
+	cancel_lend {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let borrower: T::AccountId = account("borrower", 0, 0);
+		let until = frame_system::Pallet::<T>::block_number() + T::MaxLoanDuration::get();
+		let fee = T::KittyPrice::get();
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(Pallet::<T>::lend(origin.into(), kitty_id, borrower, until, fee).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::LoanOfferCancelled { who: caller, kitty_id }.into());
+	}
+
+	borrow {
+		let owner: T::AccountId = account("owner", 0, 0);
+		fund_account::<T>(&owner);
+		let kitty_id = create_kitty::<T>(&owner, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let until = frame_system::Pallet::<T>::block_number() + T::MaxLoanDuration::get();
+		let fee = T::KittyPrice::get();
+		let origin = RawOrigin::Signed(owner.clone());
+		assert!(Pallet::<T>::lend(origin.into(), kitty_id, caller.clone(), until, fee).is_ok());
+		// 同一个区块到期的出借只差一个就满了
+		let max = T::MaxLoansPerBlock::get().saturating_sub(1);
+		let ending = (0..max).map(|i| T::KittyId::max_value() - i.into()).collect::<Vec<_>>();
+		LoansEndingAt::<T>::insert(until, BoundedVec::truncate_from(ending));
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, fee)
+	verify {
+		assert_last_event::<T>(
+			Event::KittyLent { owner, borrower: caller, kitty_id, until, fee }.into(),
+		);
+	}
+
+	fractionalize {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let kitty_id = create_kitty::<T>(&caller, 0);
+		let shares: BalanceOf<T> = SHARES.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, shares)
+	verify {
+		assert_last_event::<T>(Event::KittyFractionalized { who: caller, kitty_id, shares }.into());
+	}
+
+	redeem {
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let kitty_id = buyout_with_votes::<T>(&depositor, &buyer, 0);
+		// 赎回时取消没有投票的报价，押金从拆分 kitty 的账户转给赎回的账户
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		fill_owned_kitties::<T>(&caller);
+		give_shares::<T>(kitty_id, &depositor, &caller, SHARES);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::KittyRedeemed { who: caller, kitty_id }.into());
+	}
+
+	offer_buyout {
+		let v in 0 .. T::MaxBuyoutVoters::get();
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let kitty_id = buyout_with_votes::<T>(&depositor, &buyer, v);
+		// 替换之前的报价时退还全部投票锁定的份额
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let price = T::KittyPrice::get() * 2u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id, price)
+	verify {
+		assert_last_event::<T>(Event::BuyoutOffered { who: caller, kitty_id, price }.into());
+		assert!(Pallet::<T>::buyout_votes(kitty_id).is_empty());
+	}
+
+	cancel_buyout {
+		let v in 0 .. T::MaxBuyoutVoters::get();
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let kitty_id = buyout_with_votes::<T>(&depositor, &caller, v);
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::BuyoutCancelled { who: caller, kitty_id }.into());
+	}
+
+	vote_buyout {
+		let v in 1 .. T::MaxBuyoutVoters::get();
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let kitty_id = buyout_with_votes::<T>(&depositor, &buyer, v - 1);
+		fill_owned_kitties::<T>(&buyer);
+		// 最后一个投票者持有剩下的全部份额，投票后买断完成并向全部投票者付款
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		give_shares::<T>(kitty_id, &depositor, &caller, SHARES - (v - 1));
+		let price = T::KittyPrice::get();
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::BuyoutAccepted { buyer, kitty_id, price }.into());
+	}
+
+	claim_buyout {
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let kitty_id = buyout_with_votes::<T>(&depositor, &buyer, 0);
+		// 拆分 kitty 的账户投票完成买断，调用者兑换最后的份额并删除份额资产
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		give_shares::<T>(kitty_id, &depositor, &caller, 1);
+		let origin = RawOrigin::Signed(depositor);
+		assert!(Pallet::<T>::vote_buyout(origin.into(), kitty_id).is_ok());
+	}: _(RawOrigin::Signed(caller), kitty_id)
+	verify {
+		assert!(Pallet::<T>::buyout_claim(kitty_id).is_none());
+	}
+
+	withdraw_buyout_vote {
+		let v in 1 .. T::MaxBuyoutVoters::get();
+		let depositor: T::AccountId = account("depositor", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let kitty_id = buyout_with_votes::<T>(&depositor, &buyer, v - 1);
+		// 调用者是最后一个投票者
+		let caller: T::AccountId = whitelisted_caller();
+		give_shares::<T>(kitty_id, &depositor, &caller, 1);
+		let origin = RawOrigin::Signed(caller.clone());
+		assert!(Pallet::<T>::vote_buyout(origin.into(), kitty_id).is_ok());
+		let shares: BalanceOf<T> = 1u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), kitty_id)
+	verify {
+		assert_last_event::<T>(Event::BuyoutVoteWithdrawn { who: caller, kitty_id, shares }.into());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
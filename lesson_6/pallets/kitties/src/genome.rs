@@ -0,0 +1,184 @@
+//! Decoding of kitty DNA into named traits and trait-aware inheritance.
+//!
+//! The first bytes of the DNA hold one pair of alleles per trait, the dominant allele first:
+//!
+//! | bytes | trait     |
+//! |-------|-----------|
+//! | 0, 1  | colour    |
+//! | 2, 3  | pattern   |
+//! | 4, 5  | eye shape |
+//! | 6, 7  | rarity    |
+//!
+//! The remaining bytes carry no expressed trait and are inherited whole from either parent.
+//! For every trait a lower variant is dominant over a higher one, so common traits such as a
+//! solid coat or a common rarity tier mask the rarer ones.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug};
+
+/// The number of traits encoded in the DNA.
+pub const TRAIT_COUNT: usize = 4;
+
+/// The number of random bytes consumed by [`breed_dna`].
+pub const SEED_LENGTH: usize = 32;
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo)]
+pub enum Colour {
+	Black,
+	White,
+	Ginger,
+	Grey,
+	Cream,
+	Blue,
+	Lilac,
+	Cinnamon,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo)]
+pub enum Pattern {
+	Solid,
+	Tabby,
+	Spotted,
+	Bicolour,
+	Tortoiseshell,
+	Calico,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo)]
+pub enum EyeShape {
+	Round,
+	Almond,
+	Slanted,
+	Wide,
+}
+
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo)]
+pub enum Rarity {
+	Common,
+	Uncommon,
+	Rare,
+	Epic,
+	Legendary,
+}
+
+/// The traits expressed by a kitty's DNA.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Genome {
+	pub colour: Colour,
+	pub pattern: Pattern,
+	pub eye_shape: EyeShape,
+	pub rarity: Rarity,
+}
+
+impl Genome {
+	/// Decode the traits expressed by `dna`.
+	pub fn from_dna(dna: &[u8; 16]) -> Self {
+		Genome {
+			colour: colour(dna[0]),
+			pattern: pattern(dna[2]),
+			eye_shape: eye_shape(dna[4]),
+			rarity: rarity(dna[6]),
+		}
+	}
+}
+
+fn colour(allele: u8) -> Colour {
+	match allele % 8 {
+		0 => Colour::Black,
+		1 => Colour::White,
+		2 => Colour::Ginger,
+		3 => Colour::Grey,
+		4 => Colour::Cream,
+		5 => Colour::Blue,
+		6 => Colour::Lilac,
+		_ => Colour::Cinnamon,
+	}
+}
+
+fn pattern(allele: u8) -> Pattern {
+	match allele % 6 {
+		0 => Pattern::Solid,
+		1 => Pattern::Tabby,
+		2 => Pattern::Spotted,
+		3 => Pattern::Bicolour,
+		4 => Pattern::Tortoiseshell,
+		_ => Pattern::Calico,
+	}
+}
+
+fn eye_shape(allele: u8) -> EyeShape {
+	match allele % 4 {
+		0 => EyeShape::Round,
+		1 => EyeShape::Almond,
+		2 => EyeShape::Slanted,
+		_ => EyeShape::Wide,
+	}
+}
+
+// 稀有度按字节值分布，越高的等级越稀有
+fn rarity(allele: u8) -> Rarity {
+	match allele {
+		0..=127 => Rarity::Common,
+		128..=191 => Rarity::Uncommon,
+		192..=231 => Rarity::Rare,
+		232..=251 => Rarity::Epic,
+		_ => Rarity::Legendary,
+	}
+}
+
+/// The dominance rank of `allele` for trait `index`, lower ranks are dominant.
+fn rank(index: usize, allele: u8) -> u8 {
+	match index {
+		0 => colour(allele) as u8,
+		1 => pattern(allele) as u8,
+		2 => eye_shape(allele) as u8,
+		_ => rarity(allele) as u8,
+	}
+}
+
+/// Breed a child's DNA from two parents.
+///
+/// For every trait the child takes one allele from each parent, picked by `seed`, and each
+/// allele mutates into a random one with probability `mutation_rate`. The dominant allele of
+/// the resulting pair is stored first so that [`Genome::from_dna`] decodes the expressed trait.
+pub fn breed_dna(
+	dna_1: &[u8; 16],
+	dna_2: &[u8; 16],
+	seed: &[u8; SEED_LENGTH],
+	mutation_rate: Perbill,
+) -> [u8; 16] {
+	let mut dna = [0u8; 16];
+	let threshold = mutation_rate.mul_floor(1u32 << 16);
+
+	for index in 0..TRAIT_COUNT {
+		let selector = seed[index];
+		let mut pair = [
+			dna_1[index * 2 + (selector & 1) as usize],
+			dna_2[index * 2 + ((selector >> 1) & 1) as usize],
+		];
+
+		// 每个等位基因按突变率随机突变
+		for (i, allele) in pair.iter_mut().enumerate() {
+			let offset = 8 + index * 4 + i * 2;
+			let roll = u16::from_le_bytes([seed[offset], seed[offset + 1]]) as u32;
+			if roll < threshold {
+				*allele = seed[if i == 0 { 4 } else { 24 } + index];
+			}
+		}
+
+		if rank(index, pair[1]) < rank(index, pair[0]) {
+			pair.swap(0, 1);
+		}
+		dna[index * 2] = pair[0];
+		dna[index * 2 + 1] = pair[1];
+	}
+
+	// 不表达性状的字节整体来自父母中的一方
+	let mask = seed[28];
+	for i in TRAIT_COUNT * 2..16 {
+		dna[i] = if (mask >> (i - TRAIT_COUNT * 2)) & 1 == 0 { dna_1[i] } else { dna_2[i] };
+	}
+
+	dna
+}
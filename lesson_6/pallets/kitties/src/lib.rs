@@ -4,6 +4,7 @@
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 #[cfg(test)]
 mod mock;
@@ -11,7 +12,14 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-mod migrations;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod genome;
+pub mod migrations;
+mod nonfungible;
+pub mod randomness;
+pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -20,14 +28,23 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	use frame_support::{
-		traits::{Currency, ExistenceRequirement, Randomness},
+		traits::{
+			tokens::fungibles::{self, Create as _, Destroy as _, Inspect as _, Mutate as _},
+			BalanceStatus, Currency, ExistenceRequirement, NamedReservableCurrency,
+			OnRuntimeUpgrade, Randomness, ReservableCurrency,
+		},
 		PalletId,
 	};
-	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::{
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, Hash, One, Saturating, Zero},
+		Perbill,
+	};
 
-	use sp_io::hashing::blake2_128;
+	use sp_io::hashing::{blake2_128, blake2_256};
+
+	use crate::genome::{self, Genome};
+	use scale_info::prelude::string::String;
 
-	pub type KittyId = u32;
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -36,7 +53,118 @@ pub mod pallet {
 		pub dna: [u8; 16],
 		pub name: [u8; 8],
 	}
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	/// 繁殖相关的信息
+	#[derive(
+		Encode, Decode, Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct BreedingInfo<BlockNumber> {
+		/// 0 for created kitties, one more than the older parent for bred ones.
+		pub generation: u32,
+		/// The first block at which the kitty may breed again.
+		pub next_breed_at: BlockNumber,
+	}
+
+	/// 提交后等待揭示的繁殖承诺
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct BreedCommitment<KittyId, Hash, BlockNumber> {
+		pub kitty_id_1: KittyId,
+		pub kitty_id_2: KittyId,
+		/// The hash of the secret that has to be revealed.
+		pub commitment: Hash,
+		pub committed_at: BlockNumber,
+	}
+
+	pub type BreedCommitmentOf<T> = BreedCommitment<
+		<T as Config>::KittyId,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type AuctionOf<T> = Auction<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// 拍卖方式
+	#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub enum AuctionKind<Balance> {
+		/// Ascending bids, the highest bid at or above `reserve` wins when the auction ends.
+		English { reserve: Balance },
+		/// The price falls linearly from `start_price` to `floor_price`, the first bid at the
+		/// current price wins straight away.
+		Dutch { start_price: Balance, floor_price: Balance },
+	}
+
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Auction<AccountId, Balance, BlockNumber> {
+		pub seller: AccountId,
+		pub kind: AuctionKind<Balance>,
+		pub start: BlockNumber,
+		pub end: BlockNumber,
+		/// The current highest bidder and the amount reserved from them under
+		/// [`AUCTION_RESERVE_ID`].
+		pub highest_bid: Option<(AccountId, Balance)>,
+	}
+
+	/// 出借给别人使用的 kitty，出借期间所有者不变
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Loan<AccountId, Balance, BlockNumber> {
+		pub borrower: AccountId,
+		/// The fee the borrower pays the owner when the loan starts.
+		pub fee: Balance,
+		/// The block at which the loan ends and the kitty returns to its owner.
+		pub until: BlockNumber,
+	}
+
+	pub type LoanOf<T> = Loan<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// 拆分成份额的 kitty，kitty 由 pallet 账户持有
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct Fraction<AccountId, Balance> {
+		/// The account that fractionalized the kitty, the kitty's deposit stays reserved there.
+		pub depositor: AccountId,
+		/// The number of shares minted.
+		pub shares: Balance,
+	}
+
+	pub type FractionOf<T> = Fraction<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	/// 买断拆分的 kitty 的报价，报价金额预留在买家账户中
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct BuyoutOffer<AccountId, Balance, BlockNumber> {
+		pub buyer: AccountId,
+		pub price: Balance,
+		/// The block from which the offer can no longer be voted for and anyone can cancel it.
+		pub expires_at: BlockNumber,
+	}
+
+	pub type BuyoutOfferOf<T> = BuyoutOffer<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// 买断完成后没有投票的持有者还可以领取的款项
+	#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct BuyoutClaim<AccountId, Balance> {
+		pub buyer: AccountId,
+		/// The part of the price still reserved from the buyer.
+		pub price: Balance,
+		/// The shares not yet exchanged for their part of the price.
+		pub shares: Balance,
+	}
+
+	pub type BuyoutClaimOf<T> = BuyoutClaim<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+
+	/// 拍卖出价在出价者账户中预留时使用的标识，和押金等其他预留分开
+	pub const AUCTION_RESERVE_ID: [u8; 8] = *b"kittybid";
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -47,11 +175,79 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The identifier of a kitty.
+		type KittyId: Parameter + Member + MaxEncodedLen + Copy + AtLeast32BitUnsigned;
 		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
-		type Currency: Currency<Self::AccountId>;
+		/// The currency kitty deposits are reserved in. Auction bids are reserved under
+		/// [`AUCTION_RESERVE_ID`], so the balances pallet needs room for one named reserve.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
 		#[pallet::constant]
 		type KittyPrice: Get<BalanceOf<Self>>;
 		type PalletId: Get<PalletId>;
+		/// The longest an auction may run, in blocks.
+		#[pallet::constant]
+		type MaxAuctionDuration: Get<Self::BlockNumber>;
+		/// The maximum number of auctions that can end in the same block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+		/// The longest a kitty may be lent, in blocks.
+		#[pallet::constant]
+		type MaxLoanDuration: Get<Self::BlockNumber>;
+		/// The maximum number of loans that can end in the same block.
+		#[pallet::constant]
+		type MaxLoansPerBlock: Get<u32>;
+		/// The maximum number of kitties a single account can own.
+		#[pallet::constant]
+		type MaxKittiesOwned: Get<u32>;
+		/// The probability that an inherited allele mutates when breeding.
+		#[pallet::constant]
+		type MutationRate: Get<Perbill>;
+		/// The breeding cooldown of a generation 0 kitty, in blocks. It doubles with every
+		/// generation.
+		#[pallet::constant]
+		type BreedCooldownBase: Get<Self::BlockNumber>;
+		/// The upper bound of the breeding cooldown, in blocks.
+		#[pallet::constant]
+		type MaxBreedCooldown: Get<Self::BlockNumber>;
+		/// The number of blocks after a breed commitment in which it can be revealed. A later
+		/// reveal forfeits the commitment.
+		#[pallet::constant]
+		type BreedRevealWindow: Get<u32>;
+		/// Whether two kitties may share the same name.
+		#[pallet::constant]
+		type UniqueNames: Get<bool>;
+		/// The maximum number of items a storage migration processes per block.
+		#[pallet::constant]
+		type MigrationBatchSize: Get<u32>;
+		/// The maximum number of burned kitty ids kept for reuse, zero never reuses an id.
+		///
+		/// The id of a burned kitty is kept out of reuse while a living child records it as a
+		/// parent, or while a buyout claim or share asset of the kitty is left.
+		#[pallet::constant]
+		type MaxRecycledKittyIds: Get<u32>;
+		/// The fungible assets the shares of fractionalized kitties are minted in. The shares of a
+		/// kitty use the kitty id as their asset id, so the assets should not be used for anything
+		/// else.
+		type ShareAssets: fungibles::Inspect<
+				Self::AccountId,
+				AssetId = Self::KittyId,
+				Balance = BalanceOf<Self>,
+			> + fungibles::Mutate<Self::AccountId>
+			+ fungibles::Transfer<Self::AccountId>
+			+ fungibles::Create<Self::AccountId>
+			+ fungibles::Destroy<Self::AccountId>;
+		/// The part of the shares that has to vote for a buyout offer before it is accepted.
+		#[pallet::constant]
+		type BuyoutThreshold: Get<Perbill>;
+		/// How long a buyout offer can be voted for, in blocks. Anyone can cancel an expired offer,
+		/// which returns the shares locked by its votes.
+		#[pallet::constant]
+		type BuyoutOfferDuration: Get<Self::BlockNumber>;
+		/// The maximum number of accounts that can vote for a single buyout offer.
+		#[pallet::constant]
+		type MaxBuyoutVoters: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	// The pallet's runtime storage items.
@@ -60,33 +256,260 @@ pub mod pallet {
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
 	#[pallet::getter(fn next_kitty_id)]
-	pub type NextKittyId<T> = StorageValue<_, KittyId, ValueQuery>;
+	pub type NextKittyId<T> = StorageValue<_, T::KittyId, ValueQuery>;
+
+	/// 销毁后可以重新使用的 kitty id，后放入的先使用
+	#[pallet::storage]
+	#[pallet::getter(fn free_kitty_ids)]
+	pub type FreeKittyIds<T: Config> =
+		StorageValue<_, BoundedVec<T::KittyId, T::MaxRecycledKittyIds>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitties)]
-	pub type Kitties<T> = StorageMap<_, Blake2_128Concat, KittyId, Kitty>;
+	pub type Kitties<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, Kitty>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitty_owner)]
-	pub type KittyOwner<T: Config> = StorageMap<_, Blake2_128Concat, KittyId, T::AccountId>;
+	pub type KittyOwner<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, T::AccountId>;
+
+	/// 为每个 kitty 预留的押金，随 kitty 一起转移
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_deposit)]
+	pub type KittyDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, BalanceOf<T>, ValueQuery>;
+
+	/// 按所有者索引的 kitty
+	#[pallet::storage]
+	#[pallet::getter(fn owned_kitties)]
+	pub type OwnedKitties<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::KittyId, T::MaxKittiesOwned>,
+		ValueQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitty_parents)]
 	pub type KittyParents<T: Config> =
-		StorageMap<_, Blake2_128Concat, KittyId, (KittyId, KittyId), OptionQuery>;
+		StorageMap<_, Blake2_128Concat, T::KittyId, (T::KittyId, T::KittyId), OptionQuery>;
+
+	/// 每个 kitty 还没有销毁的子代数量，有子代的 kitty 销毁后 id 暂不重用
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_children)]
+	pub type KittyChildren<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, u32, ValueQuery>;
+
+	/// 每个 kitty 的代数和下次可以繁殖的区块，迁移前的 kitty 默认是第 0 代并可以立即繁殖
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_breeding)]
+	pub type KittyBreeding<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, BreedingInfo<T::BlockNumber>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn kitty_on_sale)]
-	pub type KittyOnSale<T: Config> = StorageMap<_, Blake2_128Concat, KittyId, (), OptionQuery>;
+	pub type KittyOnSale<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, BalanceOf<T>, OptionQuery>;
+
+	/// 提供配种的 kitty 和配种费用
+	#[pallet::storage]
+	#[pallet::getter(fn siring_offer)]
+	pub type SiringOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, BalanceOf<T>, OptionQuery>;
+
+	/// 按名字索引的 kitty，只在 `UniqueNames` 打开时使用
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_by_name)]
+	pub type KittyByName<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 8], T::KittyId>;
+
+	/// 单个 kitty 被授权给的账户，转移后清除
+	#[pallet::storage]
+	#[pallet::getter(fn approved)]
+	pub type KittyApprovals<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, T::AccountId>;
+
+	/// 所有者授权可以操作其全部 kitty 的账户
+	#[pallet::storage]
+	pub type OperatorApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// 每个账户最多有一个等待揭示的繁殖承诺
+	#[pallet::storage]
+	#[pallet::getter(fn breed_commitment)]
+	pub type BreedCommitments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BreedCommitmentOf<T>>;
+
+	/// 被繁殖承诺引用的 kitty 和提交承诺的账户，承诺被揭示、放弃或者清除时删除
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_committed_by)]
+	pub type CommittedKitties<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, T::AccountId>;
+
+	/// 还没有得到繁殖随机数的提交区块
+	#[pallet::storage]
+	pub type UnseededBreedBlocks<T: Config> =
+		StorageValue<_, BoundedVec<T::BlockNumber, T::BreedRevealWindow>, ValueQuery>;
+
+	/// 每个提交区块的繁殖随机数，在提交之后第一个新产生的随机数出现时记录，之后不再改变
+	#[pallet::storage]
+	#[pallet::getter(fn breed_seed)]
+	pub type BreedSeeds<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, T::Hash>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, AuctionOf<T>>;
+
+	/// 按结束区块索引的拍卖，在 on_initialize 中结算
+	#[pallet::storage]
+	pub type AuctionsEndingAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::KittyId, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// 等待借用者接受的出借报价
+	#[pallet::storage]
+	#[pallet::getter(fn loan_offer)]
+	pub type LoanOffers<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, LoanOf<T>>;
+
+	/// 出借中的 kitty
+	#[pallet::storage]
+	#[pallet::getter(fn kitty_loan)]
+	pub type KittyLoans<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, LoanOf<T>>;
+
+	/// 按到期区块索引的出借，在 on_initialize 中结束
+	#[pallet::storage]
+	pub type LoansEndingAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::KittyId, T::MaxLoansPerBlock>,
+		ValueQuery,
+	>;
+
+	/// 拆分成份额的 kitty
+	#[pallet::storage]
+	#[pallet::getter(fn fractions)]
+	pub type Fractions<T: Config> = StorageMap<_, Blake2_128Concat, T::KittyId, FractionOf<T>>;
+
+	/// 拆分的 kitty 当前的买断报价，每个 kitty 最多一个
+	#[pallet::storage]
+	#[pallet::getter(fn buyout_offer)]
+	pub type BuyoutOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, BuyoutOfferOf<T>>;
+
+	/// 同意买断的持有者和投票时锁定在 pallet 账户中的份额
+	#[pallet::storage]
+	#[pallet::getter(fn buyout_votes)]
+	pub type BuyoutVotes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::KittyId,
+		BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxBuyoutVoters>,
+		ValueQuery,
+	>;
+
+	/// 已经被买断、还有份额没有兑换的 kitty
+	#[pallet::storage]
+	#[pallet::getter(fn buyout_claim)]
+	pub type BuyoutClaims<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::KittyId, BuyoutClaimOf<T>>;
+
+	/// 分块进行的存储迁移的进度，迁移完成后清除
+	#[pallet::storage]
+	pub type MigrationCursor<T: Config> = StorageValue<_, migrations::Cursor>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		KittyCreated { who: T::AccountId, kitty_id: KittyId, kitty: Kitty },
-		KittyBred { who: T::AccountId, kitty_id: KittyId, kitty: Kitty },
-		KittyTransferred { who: T::AccountId, recipient: T::AccountId, kitty_id: KittyId },
-		KittyOnSale { who: T::AccountId, kitty_id: KittyId },
-		KittyBought { who: T::AccountId, kitty_id: KittyId },
+		KittyCreated { who: T::AccountId, kitty_id: T::KittyId, kitty: Kitty },
+		KittyBred { who: T::AccountId, kitty_id: T::KittyId, kitty: Kitty },
+		KittyTransferred { who: T::AccountId, recipient: T::AccountId, kitty_id: T::KittyId },
+		KittyOnSale { who: T::AccountId, kitty_id: T::KittyId, price: BalanceOf<T> },
+		KittyPriceUpdated { who: T::AccountId, kitty_id: T::KittyId, price: BalanceOf<T> },
+		KittySaleCancelled { who: T::AccountId, kitty_id: T::KittyId },
+		KittyBought {
+			who: T::AccountId,
+			seller: T::AccountId,
+			kitty_id: T::KittyId,
+			price: BalanceOf<T>,
+		},
+		AuctionCreated {
+			who: T::AccountId,
+			kitty_id: T::KittyId,
+			kind: AuctionKind<BalanceOf<T>>,
+			end: T::BlockNumber,
+		},
+		BidPlaced { who: T::AccountId, kitty_id: T::KittyId, amount: BalanceOf<T> },
+		AuctionSettled {
+			seller: T::AccountId,
+			winner: T::AccountId,
+			kitty_id: T::KittyId,
+			price: BalanceOf<T>,
+		},
+		AuctionExpired { seller: T::AccountId, kitty_id: T::KittyId },
+		/// The winner's reserved bid fell short by `missing`, the winner keeps the kitty and the
+		/// seller received the rest of the price.
+		AuctionUnderpaid {
+			seller: T::AccountId,
+			winner: T::AccountId,
+			kitty_id: T::KittyId,
+			price: BalanceOf<T>,
+			missing: BalanceOf<T>,
+		},
+		KittyBurned { who: T::AccountId, kitty_id: T::KittyId, deposit: BalanceOf<T> },
+		PalletAccountSwept { dest: T::AccountId, amount: BalanceOf<T> },
+		SiringOffered { who: T::AccountId, kitty_id: T::KittyId, fee: BalanceOf<T> },
+		SiringOfferCancelled { who: T::AccountId, kitty_id: T::KittyId },
+		SiringFeePaid {
+			who: T::AccountId,
+			sire_owner: T::AccountId,
+			sire_id: T::KittyId,
+			fee: BalanceOf<T>,
+		},
+		BreedCommitted { who: T::AccountId, kitty_id_1: T::KittyId, kitty_id_2: T::KittyId },
+		BreedCommitmentCancelled { who: T::AccountId },
+		BreedCommitmentForfeited { who: T::AccountId },
+		Approval { owner: T::AccountId, approved: Option<T::AccountId>, kitty_id: T::KittyId },
+		ApprovalForAll { owner: T::AccountId, operator: T::AccountId, approved: bool },
+		KittyRenamed { who: T::AccountId, kitty_id: T::KittyId, name: [u8; 8] },
+		LoanOffered {
+			who: T::AccountId,
+			borrower: T::AccountId,
+			kitty_id: T::KittyId,
+			until: T::BlockNumber,
+			fee: BalanceOf<T>,
+		},
+		LoanOfferCancelled { who: T::AccountId, kitty_id: T::KittyId },
+		KittyLent {
+			owner: T::AccountId,
+			borrower: T::AccountId,
+			kitty_id: T::KittyId,
+			until: T::BlockNumber,
+			fee: BalanceOf<T>,
+		},
+		LoanEnded { owner: T::AccountId, borrower: T::AccountId, kitty_id: T::KittyId },
+		KittyFractionalized { who: T::AccountId, kitty_id: T::KittyId, shares: BalanceOf<T> },
+		KittyRedeemed { who: T::AccountId, kitty_id: T::KittyId },
+		BuyoutOffered { who: T::AccountId, kitty_id: T::KittyId, price: BalanceOf<T> },
+		BuyoutCancelled { who: T::AccountId, kitty_id: T::KittyId },
+		BuyoutVoted { who: T::AccountId, kitty_id: T::KittyId, shares: BalanceOf<T> },
+		BuyoutVoteWithdrawn { who: T::AccountId, kitty_id: T::KittyId, shares: BalanceOf<T> },
+		BuyoutAccepted { buyer: T::AccountId, kitty_id: T::KittyId, price: BalanceOf<T> },
+		BuyoutClaimed {
+			who: T::AccountId,
+			kitty_id: T::KittyId,
+			shares: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -98,13 +521,74 @@ pub mod pallet {
 		NoOwner,
 		AlreadyOwned,
 		NotOnSale,
+		PriceTooHigh,
+		KittyInAuction,
+		AuctionNotExist,
+		AuctionEnded,
+		InvalidAuctionDuration,
+		InvalidAuctionPrice,
+		BidTooLow,
+		TooManyAuctions,
+		TooManyKitties,
+		BreedingCooldown,
+		RelatedKitties,
+		NotOfferedForSiring,
+		CommitmentExists,
+		NoCommitment,
+		InvalidReveal,
+		RandomnessNotReady,
+		TooManyUnseededBlocks,
+		KittyCommitted,
+		NotApproved,
+		ApproveToOwner,
+		InvalidName,
+		NameTaken,
+		KittyIdOverflow,
+		KittyLent,
+		LendToOwner,
+		InvalidLoanDuration,
+		NoLoanOffer,
+		NotBorrower,
+		TooManyLoans,
+		KittyFractionalized,
+		NotFractionalized,
+		InvalidShares,
+		NotAllShares,
+		NoShares,
+		NoBuyoutOffer,
+		NotBuyer,
+		TooManyVoters,
+		NoBuyoutClaim,
+		BuyoutExpired,
+		NotVoted,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_runtime_upgrade() -> Weight {
-			// migrations::v1::migrate::<T>()
-			migrations::v2::migrate::<T>()
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let ending = AuctionsEndingAt::<T>::take(now);
+			for kitty_id in ending.iter() {
+				Self::settle_auction(*kitty_id);
+			}
+			let mut weight = T::WeightInfo::settle_auctions(ending.len() as u32);
+
+			// 到期的出借结束，每个出借读取出借信息和所有者并删除出借信息
+			let loans = LoansEndingAt::<T>::take(now);
+			for kitty_id in loans.iter() {
+				Self::end_loan(*kitty_id);
+			}
+			let count = loans.len() as u64;
+			weight = weight
+				.saturating_add(T::DbWeight::get().reads_writes(1 + count * 2, 1 + count));
+
+			weight = weight.saturating_add(Self::seed_breed_commitments(now));
+
+			// 升级时没有迁移完的存储在后续区块中继续迁移
+			if Self::migration_in_progress() {
+				weight = weight.saturating_add(migrations::Migrations::<T>::on_runtime_upgrade());
+			}
+
+			weight
 		}
 	}
 
@@ -116,7 +600,7 @@ pub mod pallet {
 		/// An example dispatchable that takes a singles value as a parameter, writes the value to
 		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::create())]
 		pub fn create(origin: OriginFor<T>, name: [u8; 8]) -> DispatchResult {
 			// Check that the extrinsic was signed and get the signer.
 			// This function will return an error if the extrinsic is not signed.
@@ -126,11 +610,14 @@ pub mod pallet {
 			let kitty_id = Self::get_kitty_id()?;
 			let dna = Self::random_value(&who);
 			let kitty = Kitty { dna, name };
+			Self::register_name(name, kitty_id)?;
 
+			// 押金预留在所有者账户中，销毁 kitty 时退还
 			let price = T::KittyPrice::get();
-			// T::Currency::reserve(&who, price)?;
-			T::Currency::transfer(&who, &Self::get_account_id(), price, ExistenceRequirement::KeepAlive)?;
+			T::Currency::reserve(&who, price)?;
 
+			Self::add_owned_kitty(&who, kitty_id)?;
+			KittyDeposits::<T>::insert(kitty_id, price);
 			Kitties::<T>::insert(kitty_id, &kitty);
 			KittyOwner::<T>::insert(kitty_id, &who);
 
@@ -141,58 +628,32 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::breed())]
 		pub fn breed(
 			origin: OriginFor<T>,
-			kitty_id_1: KittyId,
-			kitty_id_2: KittyId,
+			kitty_id_1: T::KittyId,
+			kitty_id_2: T::KittyId,
 			name: [u8; 8]
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			ensure!(kitty_id_1 != kitty_id_2, Error::<T>::SamedKittyId);
-
 			ensure!(Kitties::<T>::contains_key(kitty_id_1), Error::<T>::InvalidKittyId);
 			ensure!(Kitties::<T>::contains_key(kitty_id_2), Error::<T>::InvalidKittyId);
 
-			let kitty_1 = Kitties::<T>::get(kitty_id_1).expect("We checked it exists");
-			let kitty_2 = Kitties::<T>::get(kitty_id_2).expect("We checked it exists");
-
-			let kitty_id = Self::get_kitty_id()?;
-
-			let selector = Self::random_value(&who);
-			let mut dna = [0u8; 16];
-			for i in 0..kitty_1.dna.len() {
-				dna[i] = selector[i] & kitty_1.dna[i] | !selector[i] & kitty_2.dna[i];
-			}
-
-			let kitty = Kitty{dna, name};
-
-			let price = T::KittyPrice::get();
-			// T::Currency::reserve(&who, price)?;
-
-			T::Currency::transfer(
-				&who,
-				&Self::get_account_id(),
-				price,
-				ExistenceRequirement::KeepAlive,
-			)?;
-
-			Kitties::<T>::insert(kitty_id, &kitty);
-			KittyOwner::<T>::insert(kitty_id, &who);
-			KittyParents::<T>::insert(kitty_id, (kitty_id_1, kitty_id_2));
-
-			Self::deposit_event(Event::KittyBred { who, kitty_id, kitty });
+			// 只能用自己的或者借来的 kitty 繁殖，别人的 kitty 需要通过 breed_with_sire 付费配种
+			ensure!(Self::kitty_user(kitty_id_1) == Some(who.clone()), Error::<T>::NotOwner);
+			ensure!(Self::kitty_user(kitty_id_2) == Some(who.clone()), Error::<T>::NotOwner);
 
-			Ok(())
+			Self::do_breed(who, kitty_id_1, kitty_id_2, name)
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			recipient: T::AccountId,
-			kitty_id: KittyId,
+			kitty_id: T::KittyId,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
@@ -200,74 +661,1368 @@ pub mod pallet {
 
 			let owner = KittyOwner::<T>::get(kitty_id).unwrap();
 			ensure!(owner == who, Error::<T>::NotOwner);
-			KittyOwner::<T>::insert(kitty_id, &recipient);
-
-			Self::deposit_event(Event::KittyTransferred { who, recipient, kitty_id });
 
-			Ok(())
+			Self::do_transfer(who, recipient, kitty_id)
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
-		pub fn sale(origin: OriginFor<T>, kitty_id: KittyId) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::sale())]
+		pub fn sale(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
 			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::NoOwner)?;
 			ensure!(owner == who, Error::<T>::NotOwner);
 			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::AlreadyOnSale);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
 
-			KittyOnSale::<T>::insert(kitty_id, ());
+			KittyOnSale::<T>::insert(kitty_id, price);
 
-			Self::deposit_event(Event::KittyOnSale { who, kitty_id });
+			Self::deposit_event(Event::KittyOnSale { who, kitty_id, price });
 
 			Ok(())
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
-		pub fn buy(origin: OriginFor<T>, kitty_id: KittyId) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			ensure!(Kitties::<T>::contains_key(kitty_id), Error::<T>::InvalidKittyId);
 			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::NoOwner)?;
 			ensure!(owner != who, Error::<T>::AlreadyOwned);
-			ensure!(KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::NotOnSale);
+			let price = Self::kitty_on_sale(kitty_id).ok_or(Error::<T>::NotOnSale)?;
+
+			// 防止卖家在购买交易打包前抬高价格
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
 
-			let price = T::KittyPrice::get();
 			T::Currency::transfer(&who, &owner, price, ExistenceRequirement::KeepAlive)?;
 
-			// T::Currency::reserve(&who, &Self::get_account_id, price,
-			// ExistenceRequirement::KeepAlive)?; T::Currency::unreserve(&owner, price);
+			Self::move_kitty(&owner, &who, kitty_id)?;
+			KittyOnSale::<T>::remove(kitty_id);
+
+			Self::deposit_event(Event::KittyBought { who, seller: owner, kitty_id, price });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::update_price())]
+		pub fn update_price(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			KittyOnSale::<T>::try_mutate(kitty_id, |listing| -> DispatchResult {
+				let current = listing.as_mut().ok_or(Error::<T>::NotOnSale)?;
+				*current = price;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::KittyPriceUpdated { who, kitty_id, price });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::cancel_sale())]
+		pub fn cancel_sale(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			ensure!(KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::NotOnSale);
 
-			KittyOwner::<T>::insert(kitty_id, &who);
 			KittyOnSale::<T>::remove(kitty_id);
 
-			Self::deposit_event(Event::KittyBought { who, kitty_id });
+			Self::deposit_event(Event::KittySaleCancelled { who, kitty_id });
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn get_kitty_id() -> Result<KittyId, DispatchError> {
-			NextKittyId::<T>::try_mutate(|next_id| -> Result<KittyId, DispatchError> {
-				let current_id = *next_id;
-				*next_id = next_id.checked_add(1).ok_or(Error::<T>::InvalidKittyId)?;
-				Ok(current_id)
-			})
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			kind: AuctionKind<BalanceOf<T>>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::AlreadyOnSale);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
+
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxAuctionDuration::get(),
+				Error::<T>::InvalidAuctionDuration
+			);
+			if let AuctionKind::Dutch { start_price, floor_price } = kind {
+				ensure!(start_price >= floor_price, Error::<T>::InvalidAuctionPrice);
+			}
+
+			let start = frame_system::Pallet::<T>::block_number();
+			let end = start.saturating_add(duration);
+			AuctionsEndingAt::<T>::try_mutate(end, |ending| ending.try_push(kitty_id))
+				.map_err(|_| Error::<T>::TooManyAuctions)?;
+
+			Auctions::<T>::insert(
+				kitty_id,
+				Auction { seller: who.clone(), kind, start, end, highest_bid: None },
+			);
+
+			Self::deposit_event(Event::AuctionCreated { who, kitty_id, kind, end });
+
+			Ok(())
 		}
 
-		fn random_value(sender: &T::AccountId) -> [u8; 16] {
-			let payload = (
-				T::Randomness::random_seed(),
-				&sender,
-				frame_system::Pallet::<T>::extrinsic_index(),
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut auction = Self::auctions(kitty_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(auction.seller != who, Error::<T>::AlreadyOwned);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < auction.end, Error::<T>::AuctionEnded);
+			ensure!(
+				(Self::owned_kitties(&who).len() as u32) < T::MaxKittiesOwned::get(),
+				Error::<T>::TooManyKitties
 			);
-			payload.using_encoded(blake2_128)
+
+			match auction.kind {
+				AuctionKind::English { reserve } => {
+					ensure!(amount >= reserve, Error::<T>::BidTooLow);
+					if let Some((_, highest)) = &auction.highest_bid {
+						ensure!(amount > *highest, Error::<T>::BidTooLow);
+					}
+
+					// 锁定新的出价，退还之前最高出价者的资金
+					T::Currency::reserve_named(&AUCTION_RESERVE_ID, &who, amount)?;
+					if let Some((bidder, highest)) = auction.highest_bid.take() {
+						T::Currency::unreserve_named(&AUCTION_RESERVE_ID, &bidder, highest);
+					}
+
+					auction.highest_bid = Some((who.clone(), amount));
+					Auctions::<T>::insert(kitty_id, auction);
+
+					Self::deposit_event(Event::BidPlaced { who, kitty_id, amount });
+				},
+				AuctionKind::Dutch { .. } => {
+					// 荷兰式拍卖第一个不低于当前价格的出价直接成交，按当前价格付款
+					let price = Self::dutch_price(&auction, now);
+					ensure!(amount >= price, Error::<T>::BidTooLow);
+
+					T::Currency::reserve_named(&AUCTION_RESERVE_ID, &who, price)?;
+					Self::deposit_event(Event::BidPlaced { who: who.clone(), kitty_id, amount: price });
+
+					AuctionsEndingAt::<T>::mutate(auction.end, |ending| {
+						ending.retain(|id| *id != kitty_id)
+					});
+					auction.highest_bid = Some((who, price));
+					Auctions::<T>::insert(kitty_id, auction);
+					Self::settle_auction(kitty_id);
+				},
+			}
+
+			Ok(())
 		}
 
-		fn get_account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			Self::do_burn(who, kitty_id)
+		}
+
+		/// 把以前创建 kitty 时转入 pallet 账户的资金转给 `dest`。pallet 账户还持有拆分的 kitty
+		/// 和投票锁定的份额，所以保留存在押金，不让账户被删除
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::sweep_pallet_account())]
+		pub fn sweep_pallet_account(origin: OriginFor<T>, dest: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let account = Self::get_account_id();
+			let amount =
+				T::Currency::free_balance(&account).saturating_sub(T::Currency::minimum_balance());
+			T::Currency::transfer(&account, &dest, amount, ExistenceRequirement::KeepAlive)?;
+
+			Self::deposit_event(Event::PalletAccountSwept { dest, amount });
+
+			Ok(())
+		}
+
+		/// 提供 kitty 给别人配种，kitty 被转移或出售时自动取消
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::offer_siring())]
+		pub fn offer_siring(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			// 出借期间只有借用者可以用 kitty 繁殖
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
+
+			// 再次调用会更新配种费用
+			SiringOffers::<T>::insert(kitty_id, fee);
+
+			Self::deposit_event(Event::SiringOffered { who, kitty_id, fee });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_siring())]
+		pub fn cancel_siring(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			ensure!(SiringOffers::<T>::contains_key(kitty_id), Error::<T>::NotOfferedForSiring);
+
+			SiringOffers::<T>::remove(kitty_id);
+
+			Self::deposit_event(Event::SiringOfferCancelled { who, kitty_id });
+
+			Ok(())
+		}
+
+		/// 用自己的 kitty 和别人提供的 kitty 配种，向对方支付配种费用
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::breed_with_sire())]
+		pub fn breed_with_sire(
+			origin: OriginFor<T>,
+			my_kitty: T::KittyId,
+			sire_id: T::KittyId,
+			max_fee: BalanceOf<T>,
+			name: [u8; 8],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(my_kitty != sire_id, Error::<T>::SamedKittyId);
+			let user = Self::kitty_user(my_kitty).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(user == who, Error::<T>::NotOwner);
+			let sire_owner = Self::kitty_owner(sire_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(sire_owner != who, Error::<T>::AlreadyOwned);
+			let fee = Self::siring_offer(sire_id).ok_or(Error::<T>::NotOfferedForSiring)?;
+
+			// 防止对方在配种交易打包前抬高费用
+			ensure!(fee <= max_fee, Error::<T>::PriceTooHigh);
+
+			T::Currency::transfer(&who, &sire_owner, fee, ExistenceRequirement::KeepAlive)?;
+			Self::deposit_event(Event::SiringFeePaid {
+				who: who.clone(),
+				sire_owner,
+				sire_id,
+				fee,
+			});
+
+			Self::do_breed(who, my_kitty, sire_id, name)
+		}
+
+		/// 提交繁殖承诺，`commitment` 是秘密值的哈希。父母立即进入冷却期，
+		/// 繁殖结果由提交之后才产生的随机数和秘密值共同决定
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::commit_breed())]
+		pub fn commit_breed(
+			origin: OriginFor<T>,
+			kitty_id_1: T::KittyId,
+			kitty_id_2: T::KittyId,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(kitty_id_1 != kitty_id_2, Error::<T>::SamedKittyId);
+			ensure!(!BreedCommitments::<T>::contains_key(&who), Error::<T>::CommitmentExists);
+			ensure!(Self::kitty_user(kitty_id_1) == Some(who.clone()), Error::<T>::NotOwner);
+			ensure!(Self::kitty_user(kitty_id_2) == Some(who.clone()), Error::<T>::NotOwner);
+			Self::ensure_not_committed(kitty_id_1)?;
+			Self::ensure_not_committed(kitty_id_2)?;
+
+			Self::start_breeding(kitty_id_1, kitty_id_2)?;
+
+			// 这个区块的提交等待下一个新产生的随机数
+			let committed_at = frame_system::Pallet::<T>::block_number();
+			UnseededBreedBlocks::<T>::try_mutate(|blocks| -> DispatchResult {
+				// 只保留本区块之前 `BreedRevealWindow - 1` 个区块，加上本区块不超过上限
+				let window: T::BlockNumber = T::BreedRevealWindow::get().into();
+				blocks.retain(|at| at.saturating_add(window) > committed_at);
+				if !blocks.contains(&committed_at) {
+					blocks
+						.try_push(committed_at)
+						.map_err(|_| Error::<T>::TooManyUnseededBlocks)?;
+				}
+				Ok(())
+			})?;
+			BreedCommitments::<T>::insert(
+				&who,
+				BreedCommitment { kitty_id_1, kitty_id_2, commitment, committed_at },
+			);
+			CommittedKitties::<T>::insert(kitty_id_1, &who);
+			CommittedKitties::<T>::insert(kitty_id_2, &who);
+
+			Self::deposit_event(Event::BreedCommitted { who, kitty_id_1, kitty_id_2 });
+
+			Ok(())
+		}
+
+		/// 揭示秘密值，完成之前提交的繁殖。超过 `BreedRevealWindow` 的承诺被作废
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::reveal_breed())]
+		pub fn reveal_breed(origin: OriginFor<T>, secret: [u8; 32], name: [u8; 8]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let commitment = Self::remove_breed_commitment(&who).ok_or(Error::<T>::NoCommitment)?;
+
+			if Self::is_commitment_expired(&commitment) {
+				Self::deposit_event(Event::BreedCommitmentForfeited { who });
+				return Ok(())
+			}
+			ensure!(T::Hashing::hash_of(&secret) == commitment.commitment, Error::<T>::InvalidReveal);
+
+			// 随机数在提交之后的第一个新随机数产生时就固定，推迟揭示不会改变结果
+			let random =
+				Self::breed_seed(commitment.committed_at).ok_or(Error::<T>::RandomnessNotReady)?;
+
+			let BreedCommitment { kitty_id_1, kitty_id_2, .. } = commitment;
+			ensure!(Self::kitty_user(kitty_id_1) == Some(who.clone()), Error::<T>::NotOwner);
+			ensure!(Self::kitty_user(kitty_id_2) == Some(who.clone()), Error::<T>::NotOwner);
+
+			let seed = (random, &who, secret).using_encoded(blake2_256);
+			Self::mint_child(who, kitty_id_1, kitty_id_2, name, seed)
+		}
+
+		/// 放弃之前提交的繁殖，父母的冷却期不会恢复
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::cancel_breed_commitment())]
+		pub fn cancel_breed_commitment(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::remove_breed_commitment(&who).ok_or(Error::<T>::NoCommitment)?;
+
+			Self::deposit_event(Event::BreedCommitmentCancelled { who });
+
+			Ok(())
 		}
+
+		/// 授权 `delegate` 转移 kitty，`None` 取消授权。所有者和所有者的操作员都可以调用
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			delegate: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(
+				owner == who || Self::is_approved_for_all(&owner, &who),
+				Error::<T>::NotApproved
+			);
+
+			match &delegate {
+				Some(delegate) => {
+					ensure!(*delegate != owner, Error::<T>::ApproveToOwner);
+					KittyApprovals::<T>::insert(kitty_id, delegate);
+				},
+				None => KittyApprovals::<T>::remove(kitty_id),
+			}
+
+			Self::deposit_event(Event::Approval { owner, approved: delegate, kitty_id });
+
+			Ok(())
+		}
+
+		/// 授权或取消授权 `operator` 操作调用者的全部 kitty
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(operator != who, Error::<T>::ApproveToOwner);
+
+			if approved {
+				OperatorApprovals::<T>::insert(&who, &operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(&who, &operator);
+			}
+
+			Self::deposit_event(Event::ApprovalForAll { owner: who, operator, approved });
+
+			Ok(())
+		}
+
+		/// 由所有者、被授权的账户或操作员把 `from` 的 kitty 转给 `to`
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			kitty_id: T::KittyId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == from, Error::<T>::NotOwner);
+			ensure!(Self::is_approved_or_owner(&who, &owner, kitty_id), Error::<T>::NotApproved);
+
+			Self::do_transfer(owner, to, kitty_id)
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::rename())]
+		pub fn rename(origin: OriginFor<T>, kitty_id: T::KittyId, name: [u8; 8]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+
+			Kitties::<T>::try_mutate(kitty_id, |kitty| -> DispatchResult {
+				let kitty = kitty.as_mut().ok_or(Error::<T>::InvalidKittyId)?;
+				Self::unregister_name(kitty.name, kitty_id);
+				Self::register_name(name, kitty_id)?;
+				kitty.name = name;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::KittyRenamed { who, kitty_id, name });
+
+			Ok(())
+		}
+
+		/// 把 kitty 出借给 `borrower` 到 `until` 区块，`borrower` 调用 `borrow` 并支付 `fee`
+		/// 后生效。出借期间所有者不变，但只有借用者可以用它繁殖，kitty 不能被转移、出售或销毁
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::lend())]
+		pub fn lend(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			borrower: T::AccountId,
+			until: T::BlockNumber,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			ensure!(borrower != who, Error::<T>::LendToOwner);
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::AlreadyOnSale);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				until > now && until - now <= T::MaxLoanDuration::get(),
+				Error::<T>::InvalidLoanDuration
+			);
+
+			// 再次调用会替换之前的报价
+			LoanOffers::<T>::insert(kitty_id, Loan { borrower: borrower.clone(), fee, until });
+
+			Self::deposit_event(Event::LoanOffered { who, borrower, kitty_id, until, fee });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::cancel_lend())]
+		pub fn cancel_lend(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			ensure!(LoanOffers::<T>::contains_key(kitty_id), Error::<T>::NoLoanOffer);
+
+			LoanOffers::<T>::remove(kitty_id);
+
+			Self::deposit_event(Event::LoanOfferCancelled { who, kitty_id });
+
+			Ok(())
+		}
+
+		/// 接受出借报价并向所有者支付费用，出借到期后在 on_initialize 中自动结束
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::borrow())]
+		pub fn borrow(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let loan = Self::loan_offer(kitty_id).ok_or(Error::<T>::NoLoanOffer)?;
+			ensure!(loan.borrower == who, Error::<T>::NotBorrower);
+
+			// 防止所有者在交易打包前提高费用
+			ensure!(loan.fee <= max_fee, Error::<T>::PriceTooHigh);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(loan.until > now, Error::<T>::InvalidLoanDuration);
+
+			// 报价之后 kitty 可能又被上架或者拍卖
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::AlreadyOnSale);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+
+			LoansEndingAt::<T>::try_mutate(loan.until, |ending| ending.try_push(kitty_id))
+				.map_err(|_| Error::<T>::TooManyLoans)?;
+			T::Currency::transfer(&who, &owner, loan.fee, ExistenceRequirement::KeepAlive)?;
+
+			LoanOffers::<T>::remove(kitty_id);
+			// 出借期间所有者的配种报价失效
+			SiringOffers::<T>::remove(kitty_id);
+			KittyLoans::<T>::insert(kitty_id, &loan);
+
+			Self::deposit_event(Event::KittyLent {
+				owner,
+				borrower: who,
+				kitty_id,
+				until: loan.until,
+				fee: loan.fee,
+			});
+
+			Ok(())
+		}
+
+		/// 把 kitty 锁定在 pallet 账户中，并向所有者发行 `shares` 份份额。份额可以通过
+		/// `ShareAssets` 转让，持有全部份额的账户可以赎回 kitty
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::fractionalize())]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			shares: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+			ensure!(owner == who, Error::<T>::NotOwner);
+			ensure!(!shares.is_zero(), Error::<T>::InvalidShares);
+			ensure!(!KittyOnSale::<T>::contains_key(kitty_id), Error::<T>::AlreadyOnSale);
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
+
+			// 买断后还有份额没有兑换时资产仍然存在，这里会失败。份额资产不需要持有者有余额，
+			// 这样没有余额的 pallet 账户也可以锁定投票的份额
+			T::ShareAssets::create(kitty_id, Self::get_account_id(), true, One::one())?;
+			T::ShareAssets::mint_into(kitty_id, &who, shares)?;
+
+			// pallet 账户持有的 kitty 不计入所有者索引，不受 MaxKittiesOwned 限制
+			Self::remove_owned_kitty(&who, kitty_id);
+			KittyOwner::<T>::insert(kitty_id, Self::get_account_id());
+			SiringOffers::<T>::remove(kitty_id);
+			LoanOffers::<T>::remove(kitty_id);
+			KittyApprovals::<T>::remove(kitty_id);
+			Fractions::<T>::insert(kitty_id, Fraction { depositor: who.clone(), shares });
+
+			Self::deposit_event(Event::KittyFractionalized { who, kitty_id, shares });
+
+			Ok(())
+		}
+
+		/// 销毁全部份额，取回拆分的 kitty
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::redeem())]
+		pub fn redeem(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let fraction = Self::fractions(kitty_id).ok_or(Error::<T>::NotFractionalized)?;
+
+			// 投票的份额锁定在 pallet 账户中，投票撤回之前不能赎回
+			let shares = T::ShareAssets::balance(kitty_id, &who);
+			ensure!(shares == fraction.shares, Error::<T>::NotAllShares);
+
+			// 没有投票的买断报价直接取消
+			if let Some(offer) = Self::buyout_offer(kitty_id) {
+				Self::drop_buyout(kitty_id, &offer)?;
+				Self::deposit_event(Event::BuyoutCancelled { who: offer.buyer, kitty_id });
+			}
+
+			Self::release_kitty(kitty_id, &fraction.depositor, &who)?;
+			T::ShareAssets::burn_from(kitty_id, &who, shares)?;
+			Self::destroy_shares(kitty_id);
+
+			Self::deposit_event(Event::KittyRedeemed { who, kitty_id });
+
+			Ok(())
+		}
+
+		/// 出价买断拆分的 kitty，出价预留在调用者账户中，报价在 `BuyoutOfferDuration` 个区块
+		/// 后过期。更高的出价替换之前的报价，过期的报价可以被任何出价替换。被替换的报价的
+		/// 投票作废，锁定的份额退还给投票者
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::offer_buyout(T::MaxBuyoutVoters::get()))]
+		pub fn offer_buyout(
+			origin: OriginFor<T>,
+			kitty_id: T::KittyId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Fractions::<T>::contains_key(kitty_id), Error::<T>::NotFractionalized);
+			ensure!(
+				(Self::owned_kitties(&who).len() as u32) < T::MaxKittiesOwned::get(),
+				Error::<T>::TooManyKitties
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(offer) = Self::buyout_offer(kitty_id) {
+				ensure!(price > offer.price || offer.expires_at <= now, Error::<T>::BidTooLow);
+				// 投票者同意的是之前的价格，需要重新投票
+				Self::drop_buyout(kitty_id, &offer)?;
+			}
+			T::Currency::reserve(&who, price)?;
+
+			let expires_at = now.saturating_add(T::BuyoutOfferDuration::get());
+			let offer = BuyoutOffer { buyer: who.clone(), price, expires_at };
+			BuyoutOffers::<T>::insert(kitty_id, offer);
+
+			Self::deposit_event(Event::BuyoutOffered { who, kitty_id, price });
+
+			Ok(())
+		}
+
+		/// 买家随时可以取消买断报价，报价过期后任何人都可以取消。锁定的份额退还给投票者
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::cancel_buyout(T::MaxBuyoutVoters::get()))]
+		pub fn cancel_buyout(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Self::buyout_offer(kitty_id).ok_or(Error::<T>::NoBuyoutOffer)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(offer.buyer == who || offer.expires_at <= now, Error::<T>::NotBuyer);
+
+			Self::drop_buyout(kitty_id, &offer)?;
+
+			Self::deposit_event(Event::BuyoutCancelled { who: offer.buyer, kitty_id });
+
+			Ok(())
+		}
+
+		/// 用调用者持有的全部份额同意当前的买断报价，投票的份额锁定在 pallet 账户中，买断完成
+		/// 时销毁。同意的份额达到 `BuyoutThreshold` 后买断完成，投票者按份额分得出价
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::vote_buyout(T::MaxBuyoutVoters::get()))]
+		pub fn vote_buyout(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let fraction = Self::fractions(kitty_id).ok_or(Error::<T>::NotFractionalized)?;
+			let offer = Self::buyout_offer(kitty_id).ok_or(Error::<T>::NoBuyoutOffer)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < offer.expires_at, Error::<T>::BuyoutExpired);
+			let shares = T::ShareAssets::balance(kitty_id, &who);
+			ensure!(!shares.is_zero(), Error::<T>::NoShares);
+
+			let mut votes = Self::buyout_votes(kitty_id);
+			// 同一个账户再次投票时合并
+			match votes.iter_mut().find(|(voter, _)| *voter == who) {
+				Some((_, voted)) => *voted = voted.saturating_add(shares),
+				None =>
+					votes.try_push((who.clone(), shares)).map_err(|_| Error::<T>::TooManyVoters)?,
+			}
+			Self::transfer_shares(kitty_id, &who, &Self::get_account_id(), shares)?;
+			BuyoutVotes::<T>::insert(kitty_id, &votes);
+
+			Self::deposit_event(Event::BuyoutVoted { who, kitty_id, shares });
+
+			let voted = votes
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |total, (_, shares)| total.saturating_add(*shares));
+			if voted < T::BuyoutThreshold::get().mul_ceil(fraction.shares) {
+				return Ok(())
+			}
+
+			// 买家拥有的 kitty 已经达到上限时无法交付，取消报价，否则之后的投票都会失败
+			if (Self::owned_kitties(&offer.buyer).len() as u32) < T::MaxKittiesOwned::get() {
+				Self::accept_buyout(kitty_id, fraction, offer)?;
+			} else {
+				Self::drop_buyout(kitty_id, &offer)?;
+				Self::deposit_event(Event::BuyoutCancelled { who: offer.buyer, kitty_id });
+			}
+
+			Ok(())
+		}
+
+		/// 买断完成后，没有投票的持有者销毁份额并领取对应的款项
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::claim_buyout())]
+		pub fn claim_buyout(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut claim = Self::buyout_claim(kitty_id).ok_or(Error::<T>::NoBuyoutClaim)?;
+			let shares = T::ShareAssets::balance(kitty_id, &who);
+			ensure!(!shares.is_zero(), Error::<T>::NoShares);
+
+			T::ShareAssets::burn_from(kitty_id, &who, shares)?;
+			let amount = Self::buyout_share(claim.price, claim.shares, shares);
+			T::Currency::repatriate_reserved(&claim.buyer, &who, amount, BalanceStatus::Free)?;
+
+			claim.price = claim.price.saturating_sub(amount);
+			claim.shares = claim.shares.saturating_sub(shares);
+			if claim.shares.is_zero() {
+				BuyoutClaims::<T>::remove(kitty_id);
+				Self::destroy_shares(kitty_id);
+				// 买下的 kitty 可能已经销毁了
+				Self::recycle_kitty_id(kitty_id);
+			} else {
+				BuyoutClaims::<T>::insert(kitty_id, claim);
+			}
+
+			Self::deposit_event(Event::BuyoutClaimed { who, kitty_id, shares, amount });
+
+			Ok(())
+		}
+
+		/// 撤回对当前买断报价的投票，取回锁定的份额
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::withdraw_buyout_vote(T::MaxBuyoutVoters::get()))]
+		pub fn withdraw_buyout_vote(origin: OriginFor<T>, kitty_id: T::KittyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut votes = Self::buyout_votes(kitty_id);
+			let index =
+				votes.iter().position(|(voter, _)| *voter == who).ok_or(Error::<T>::NotVoted)?;
+			let (_, shares) = votes.remove(index);
+
+			Self::transfer_shares(kitty_id, &Self::get_account_id(), &who, shares)?;
+			if votes.is_empty() {
+				BuyoutVotes::<T>::remove(kitty_id);
+			} else {
+				BuyoutVotes::<T>::insert(kitty_id, &votes);
+			}
+
+			Self::deposit_event(Event::BuyoutVoteWithdrawn { who, kitty_id, shares });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn get_kitty_id() -> Result<T::KittyId, DispatchError> {
+			// 优先重用销毁的 kitty 的 id
+			if let Some(kitty_id) = FreeKittyIds::<T>::mutate(|free| free.pop()) {
+				return Ok(kitty_id)
+			}
+
+			NextKittyId::<T>::try_mutate(|next_id| -> Result<T::KittyId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(&One::one()).ok_or(Error::<T>::KittyIdOverflow)?;
+				Ok(current_id)
+			})
+		}
+
+		/// 销毁的 kitty 的 id 是否还被子代、买断款项或者份额资产引用
+		pub(crate) fn is_kitty_id_referenced(kitty_id: T::KittyId) -> bool {
+			KittyChildren::<T>::contains_key(kitty_id) ||
+				BuyoutClaims::<T>::contains_key(kitty_id) ||
+				T::ShareAssets::asset_exists(kitty_id)
+		}
+
+		/// 把已经销毁、不再被引用的 kitty 的 id 放入空闲列表，空闲列表满了以后不再重用。
+		/// 停在删除中状态的份额资产会让 id 一直不被重用
+		fn recycle_kitty_id(kitty_id: T::KittyId) {
+			if Kitties::<T>::contains_key(kitty_id) || Self::is_kitty_id_referenced(kitty_id) {
+				return
+			}
+			FreeKittyIds::<T>::mutate(|free| {
+				let _ = free.try_push(kitty_id);
+			});
+		}
+
+		/// 把 `owner` 的 kitty 转给 `recipient`，调用者已经检查过所有权
+		pub(crate) fn do_transfer(
+			owner: T::AccountId,
+			recipient: T::AccountId,
+			kitty_id: T::KittyId,
+		) -> DispatchResult {
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
+			ensure!(!Fractions::<T>::contains_key(kitty_id), Error::<T>::KittyFractionalized);
+			Self::move_kitty(&owner, &recipient, kitty_id)?;
+			// 转移后原来的上架信息失效
+			KittyOnSale::<T>::remove(kitty_id);
+
+			Self::deposit_event(Event::KittyTransferred { who: owner, recipient, kitty_id });
+
+			Ok(())
+		}
+
+		/// 销毁 `owner` 的 kitty 并退还押金，调用者已经检查过所有权
+		pub(crate) fn do_burn(owner: T::AccountId, kitty_id: T::KittyId) -> DispatchResult {
+			ensure!(!Auctions::<T>::contains_key(kitty_id), Error::<T>::KittyInAuction);
+			ensure!(!KittyLoans::<T>::contains_key(kitty_id), Error::<T>::KittyLent);
+			ensure!(!Fractions::<T>::contains_key(kitty_id), Error::<T>::KittyFractionalized);
+			// 销毁后 id 会被重用，不能留下还能揭示的繁殖承诺
+			Self::ensure_not_committed(kitty_id)?;
+
+			if let Some(kitty) = Kitties::<T>::take(kitty_id) {
+				Self::unregister_name(kitty.name, kitty_id);
+			}
+			KittyOwner::<T>::remove(kitty_id);
+			let parents = KittyParents::<T>::take(kitty_id);
+			KittyBreeding::<T>::remove(kitty_id);
+			KittyOnSale::<T>::remove(kitty_id);
+			SiringOffers::<T>::remove(kitty_id);
+			KittyApprovals::<T>::remove(kitty_id);
+			LoanOffers::<T>::remove(kitty_id);
+			Self::remove_owned_kitty(&owner, kitty_id);
+
+			Self::recycle_kitty_id(kitty_id);
+			// 父母少了一个子代，已经销毁的父母不再被引用时可以重用它的 id
+			if let Some((parent_1, parent_2)) = parents {
+				for parent_id in [parent_1, parent_2] {
+					KittyChildren::<T>::mutate_exists(parent_id, |children| {
+						*children = children.map(|n| n.saturating_sub(1)).filter(|n| *n > 0);
+					});
+					Self::recycle_kitty_id(parent_id);
+				}
+			}
+
+			// 退还押金
+			let deposit = KittyDeposits::<T>::take(kitty_id);
+			T::Currency::unreserve(&owner, deposit);
+
+			Self::deposit_event(Event::KittyBurned { who: owner, kitty_id, deposit });
+
+			Ok(())
+		}
+
+		/// 用两个 kitty 繁殖出新的 kitty，调用者已经检查过所有权
+		fn do_breed(
+			who: T::AccountId,
+			kitty_id_1: T::KittyId,
+			kitty_id_2: T::KittyId,
+			name: [u8; 8],
+		) -> DispatchResult {
+			Self::start_breeding(kitty_id_1, kitty_id_2)?;
+			let seed = Self::random_seed(&who);
+			Self::mint_child(who, kitty_id_1, kitty_id_2, name, seed)
+		}
+
+		/// 检查两个 kitty 可以繁殖，并让它们进入冷却期
+		fn start_breeding(kitty_id_1: T::KittyId, kitty_id_2: T::KittyId) -> DispatchResult {
+			ensure!(Kitties::<T>::contains_key(kitty_id_1), Error::<T>::InvalidKittyId);
+			ensure!(Kitties::<T>::contains_key(kitty_id_2), Error::<T>::InvalidKittyId);
+
+			// 亲子或者有共同父母的 kitty 不能繁殖
+			ensure!(!Self::are_related(kitty_id_1, kitty_id_2), Error::<T>::RelatedKitties);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let breeding_1 = Self::kitty_breeding(kitty_id_1);
+			let breeding_2 = Self::kitty_breeding(kitty_id_2);
+			ensure!(
+				breeding_1.next_breed_at <= now && breeding_2.next_breed_at <= now,
+				Error::<T>::BreedingCooldown
+			);
+
+			// 父母进入冷却期
+			for (parent_id, mut breeding) in [(kitty_id_1, breeding_1), (kitty_id_2, breeding_2)] {
+				breeding.next_breed_at = now.saturating_add(Self::breed_cooldown(breeding.generation));
+				KittyBreeding::<T>::insert(parent_id, breeding);
+			}
+
+			Ok(())
+		}
+
+		/// 删除 `who` 的繁殖承诺和它对父母的引用
+		fn remove_breed_commitment(who: &T::AccountId) -> Option<BreedCommitmentOf<T>> {
+			let commitment = BreedCommitments::<T>::take(who)?;
+			CommittedKitties::<T>::remove(commitment.kitty_id_1);
+			CommittedKitties::<T>::remove(commitment.kitty_id_2);
+			Some(commitment)
+		}
+
+		/// 超过 `BreedRevealWindow` 的承诺已经作废
+		fn is_commitment_expired(commitment: &BreedCommitmentOf<T>) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			now > commitment.committed_at.saturating_add(T::BreedRevealWindow::get().into())
+		}
+
+		/// kitty 被还在揭示窗口内的繁殖承诺引用时返回错误，引用它的作废承诺直接清除
+		fn ensure_not_committed(kitty_id: T::KittyId) -> DispatchResult {
+			if let Some(who) = CommittedKitties::<T>::get(kitty_id) {
+				if let Some(commitment) = Self::breed_commitment(&who) {
+					ensure!(Self::is_commitment_expired(&commitment), Error::<T>::KittyCommitted);
+				}
+				Self::remove_breed_commitment(&who);
+				Self::deposit_event(Event::BreedCommitmentForfeited { who });
+			}
+			Ok(())
+		}
+
+		/// 用 `seed` 混合父母的 DNA，生成新的 kitty
+		fn mint_child(
+			who: T::AccountId,
+			kitty_id_1: T::KittyId,
+			kitty_id_2: T::KittyId,
+			name: [u8; 8],
+			seed: [u8; genome::SEED_LENGTH],
+		) -> DispatchResult {
+			let kitty_1 = Kitties::<T>::get(kitty_id_1).ok_or(Error::<T>::InvalidKittyId)?;
+			let kitty_2 = Kitties::<T>::get(kitty_id_2).ok_or(Error::<T>::InvalidKittyId)?;
+
+			let kitty_id = Self::get_kitty_id()?;
+
+			// 按性状的显隐性遗传，并有一定概率突变
+			let dna = genome::breed_dna(&kitty_1.dna, &kitty_2.dna, &seed, T::MutationRate::get());
+
+			let kitty = Kitty{dna, name};
+			Self::register_name(name, kitty_id)?;
+
+			let price = T::KittyPrice::get();
+			T::Currency::reserve(&who, price)?;
+
+			Self::add_owned_kitty(&who, kitty_id)?;
+			KittyDeposits::<T>::insert(kitty_id, price);
+			Kitties::<T>::insert(kitty_id, &kitty);
+			KittyOwner::<T>::insert(kitty_id, &who);
+			KittyParents::<T>::insert(kitty_id, (kitty_id_1, kitty_id_2));
+			for parent_id in [kitty_id_1, kitty_id_2] {
+				KittyChildren::<T>::mutate(parent_id, |children| {
+					*children = children.saturating_add(1)
+				});
+			}
+
+			// 子代的代数比父母中较大的一代多一
+			let now = frame_system::Pallet::<T>::block_number();
+			let generation = Self::kitty_breeding(kitty_id_1)
+				.generation
+				.max(Self::kitty_breeding(kitty_id_2).generation)
+				.saturating_add(1);
+			KittyBreeding::<T>::insert(
+				kitty_id,
+				BreedingInfo {
+					generation,
+					next_breed_at: now.saturating_add(Self::breed_cooldown(generation)),
+				},
+			);
+
+			Self::deposit_event(Event::KittyBred { who, kitty_id, kitty });
+
+			Ok(())
+		}
+
+		/// 为还没有随机数的提交区块记录随机数，随机数必须在提交区块之后才产生
+		fn seed_breed_commitments(now: T::BlockNumber) -> Weight {
+			// 揭示窗口已经过去的随机数不再需要
+			let window: T::BlockNumber = T::BreedRevealWindow::get().into();
+			if now > window {
+				BreedSeeds::<T>::remove(now - window - One::one());
+			}
+
+			let mut blocks = UnseededBreedBlocks::<T>::get();
+			if blocks.is_empty() {
+				return T::DbWeight::get().reads_writes(1, 1)
+			}
+
+			let (random, known_since) = T::Randomness::random(b"kitties/breed");
+			let mut seeded = 0u64;
+			blocks.retain(|at| {
+				if at.saturating_add(window) < now {
+					false
+				} else if known_since > *at {
+					BreedSeeds::<T>::insert(at, (random, at).using_encoded(T::Hashing::hash));
+					seeded += 1;
+					false
+				} else {
+					true
+				}
+			});
+			UnseededBreedBlocks::<T>::put(blocks);
+
+			// 读取等待的区块和随机数来源，写入随机数
+			T::DbWeight::get().reads_writes(4, 2 + seeded)
+		}
+
+		fn random_value(sender: &T::AccountId) -> [u8; 16] {
+			let payload = (
+				T::Randomness::random_seed(),
+				&sender,
+				frame_system::Pallet::<T>::extrinsic_index(),
+			);
+			payload.using_encoded(blake2_128)
+		}
+
+		fn random_seed(sender: &T::AccountId) -> [u8; genome::SEED_LENGTH] {
+			let payload = (
+				T::Randomness::random_seed(),
+				&sender,
+				frame_system::Pallet::<T>::extrinsic_index(),
+			);
+			payload.using_encoded(blake2_256)
+		}
+
+		/// 可以用 kitty 繁殖的账户，出借期间是借用者，否则是所有者
+		pub fn kitty_user(kitty_id: T::KittyId) -> Option<T::AccountId> {
+			match Self::kitty_loan(kitty_id) {
+				Some(loan) => Some(loan.borrower),
+				None => Self::kitty_owner(kitty_id),
+			}
+		}
+
+		/// 结束到期的出借，kitty 的使用权回到所有者
+		fn end_loan(kitty_id: T::KittyId) {
+			if let Some(loan) = KittyLoans::<T>::take(kitty_id) {
+				if let Some(owner) = Self::kitty_owner(kitty_id) {
+					Self::deposit_event(Event::LoanEnded {
+						owner,
+						borrower: loan.borrower,
+						kitty_id,
+					});
+				}
+			}
+		}
+
+		/// 完成买断：向投票者支付，把 kitty 交给买家，剩下的款项留给没有投票的持有者领取
+		fn accept_buyout(
+			kitty_id: T::KittyId,
+			fraction: FractionOf<T>,
+			offer: BuyoutOfferOf<T>,
+		) -> DispatchResult {
+			let account = Self::get_account_id();
+			let mut price = offer.price;
+			let mut shares = fraction.shares;
+			for (voter, voted) in BuyoutVotes::<T>::take(kitty_id) {
+				let amount = Self::buyout_share(price, shares, voted);
+				let paid = T::Currency::repatriate_reserved(
+					&offer.buyer,
+					&voter,
+					amount,
+					BalanceStatus::Free,
+				);
+				if paid.is_ok() {
+					price = price.saturating_sub(amount);
+					shares = shares.saturating_sub(voted);
+				} else {
+					// 份额不需要持有者有余额，没有账户的投票者收不到付款，取回份额以后自己领取
+					Self::transfer_shares(kitty_id, &account, &voter, voted)?;
+				}
+			}
+
+			// 投票锁定的份额兑换成了出价
+			let locked = fraction.shares.saturating_sub(shares);
+			T::ShareAssets::burn_from(kitty_id, &account, locked)?;
+
+			Self::release_kitty(kitty_id, &fraction.depositor, &offer.buyer)?;
+			BuyoutOffers::<T>::remove(kitty_id);
+			if shares.is_zero() {
+				Self::destroy_shares(kitty_id);
+			} else {
+				BuyoutClaims::<T>::insert(
+					kitty_id,
+					BuyoutClaim { buyer: offer.buyer.clone(), price, shares },
+				);
+			}
+
+			Self::deposit_event(Event::BuyoutAccepted {
+				buyer: offer.buyer,
+				kitty_id,
+				price: offer.price,
+			});
+
+			Ok(())
+		}
+
+		/// 剩余的 `total` 份份额分剩余的 `price` 时 `shares` 份份额分得的款项。比例向下取整，
+		/// 最后兑换的份额分得全部余额，不会多付也不会留下零头
+		fn buyout_share(
+			price: BalanceOf<T>,
+			total: BalanceOf<T>,
+			shares: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			if shares >= total {
+				return price
+			}
+			Perbill::from_rational(shares, total).mul_floor(price)
+		}
+
+		/// 把 pallet 账户持有的拆分的 kitty 交给 `to`，押金从拆分 kitty 的账户转给 `to`
+		fn release_kitty(
+			kitty_id: T::KittyId,
+			depositor: &T::AccountId,
+			to: &T::AccountId,
+		) -> DispatchResult {
+			Self::add_owned_kitty(to, kitty_id)?;
+
+			let deposit = KittyDeposits::<T>::get(kitty_id);
+			if depositor != to && !deposit.is_zero() {
+				let missing = T::Currency::repatriate_reserved(
+					depositor,
+					to,
+					deposit,
+					BalanceStatus::Reserved,
+				)?;
+				KittyDeposits::<T>::insert(kitty_id, deposit.saturating_sub(missing));
+			}
+
+			KittyOwner::<T>::insert(kitty_id, to);
+			Fractions::<T>::remove(kitty_id);
+			Ok(())
+		}
+
+		/// 取消买断报价：退还买家预留的出价，把投票锁定的份额还给投票者
+		fn drop_buyout(kitty_id: T::KittyId, offer: &BuyoutOfferOf<T>) -> DispatchResult {
+			let account = Self::get_account_id();
+			for (voter, shares) in BuyoutVotes::<T>::take(kitty_id) {
+				Self::transfer_shares(kitty_id, &account, &voter, shares)?;
+			}
+			T::Currency::unreserve(&offer.buyer, offer.price);
+			BuyoutOffers::<T>::remove(kitty_id);
+			Ok(())
+		}
+
+		fn transfer_shares(
+			kitty_id: T::KittyId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			shares: BalanceOf<T>,
+		) -> DispatchResult {
+			<T::ShareAssets as fungibles::Transfer<T::AccountId>>::transfer(
+				kitty_id,
+				from,
+				to,
+				shares,
+				false,
+			)?;
+			Ok(())
+		}
+
+		/// 删除份额已经全部销毁的资产
+		fn destroy_shares(kitty_id: T::KittyId) {
+			// 持有者在资产上设置的授权会让删除失败，资产会停在删除中的状态，
+			// 任何人都可以通过资产 pallet 清除授权并完成删除
+			let _ = T::ShareAssets::start_destroy(kitty_id, None)
+				.and_then(|_| T::ShareAssets::finish_destroy(kitty_id));
+		}
+
+		/// `operator` 是否可以操作 `owner` 的全部 kitty
+		pub fn is_approved_for_all(owner: &T::AccountId, operator: &T::AccountId) -> bool {
+			OperatorApprovals::<T>::contains_key(owner, operator)
+		}
+
+		fn is_approved_or_owner(
+			who: &T::AccountId,
+			owner: &T::AccountId,
+			kitty_id: T::KittyId,
+		) -> bool {
+			who == owner ||
+				Self::approved(kitty_id).as_ref() == Some(who) ||
+				Self::is_approved_for_all(owner, who)
+		}
+
+		/// kitty 的名字，末尾的 0 不算在内
+		pub fn name_of(kitty_id: T::KittyId) -> Option<String> {
+			Self::kitties(kitty_id).and_then(|kitty| decode_name(&kitty.name).map(Into::into))
+		}
+
+		/// 检查名字并在需要时加入名字索引
+		fn register_name(name: [u8; 8], kitty_id: T::KittyId) -> DispatchResult {
+			ensure!(decode_name(&name).is_some(), Error::<T>::InvalidName);
+			if T::UniqueNames::get() {
+				ensure!(!KittyByName::<T>::contains_key(name), Error::<T>::NameTaken);
+				KittyByName::<T>::insert(name, kitty_id);
+			}
+			Ok(())
+		}
+
+		fn unregister_name(name: [u8; 8], kitty_id: T::KittyId) {
+			KittyByName::<T>::mutate_exists(name, |indexed| {
+				if *indexed == Some(kitty_id) {
+					*indexed = None;
+				}
+			});
+		}
+
+		/// 是否有存储迁移还没有完成，迁移期间 runtime 应当拒绝 kitty 相关的交易
+		pub fn migration_in_progress() -> bool {
+			MigrationCursor::<T>::exists()
+		}
+
+		/// kitty 的 DNA 所表达的性状
+		pub fn traits_of(kitty_id: T::KittyId) -> Option<Genome> {
+			Self::kitties(kitty_id).map(|kitty| Genome::from_dna(&kitty.dna))
+		}
+
+		/// 第 `generation` 代 kitty 繁殖后的冷却期
+		pub fn breed_cooldown(generation: u32) -> T::BlockNumber {
+			let factor = 2u32.saturating_pow(generation);
+			T::BreedCooldownBase::get()
+				.saturating_mul(factor.into())
+				.min(T::MaxBreedCooldown::get())
+		}
+
+		/// 两个 kitty 是否是亲子或者有共同的父母
+		fn are_related(kitty_id_1: T::KittyId, kitty_id_2: T::KittyId) -> bool {
+			let parents_1 = Self::kitty_parents(kitty_id_1);
+			let parents_2 = Self::kitty_parents(kitty_id_2);
+
+			let is_parent_of = |parents: Option<(T::KittyId, T::KittyId)>, kitty_id: T::KittyId| {
+				parents.map_or(false, |(p1, p2)| p1 == kitty_id || p2 == kitty_id)
+			};
+			if is_parent_of(parents_1, kitty_id_2) || is_parent_of(parents_2, kitty_id_1) {
+				return true
+			}
+
+			match (parents_1, parents_2) {
+				(Some((a1, a2)), Some((b1, b2))) => a1 == b1 || a1 == b2 || a2 == b1 || a2 == b2,
+				_ => false,
+			}
+		}
+
+		/// 荷兰式拍卖在 `now` 时的价格
+		pub fn dutch_price(auction: &AuctionOf<T>, now: T::BlockNumber) -> BalanceOf<T> {
+			match auction.kind {
+				AuctionKind::Dutch { start_price, floor_price } => {
+					let elapsed = now.saturating_sub(auction.start);
+					let duration = auction.end.saturating_sub(auction.start);
+					let decay = Perbill::from_rational(elapsed, duration)
+						.mul_floor(start_price.saturating_sub(floor_price));
+					start_price.saturating_sub(decay)
+				},
+				AuctionKind::English { reserve } => reserve,
+			}
+		}
+
+		/// 结算拍卖：有出价时把 kitty 交给最高出价者并向卖家支付，否则直接结束
+		fn settle_auction(kitty_id: T::KittyId) {
+			let auction = match Auctions::<T>::take(kitty_id) {
+				Some(auction) => auction,
+				None => return,
+			};
+
+			match auction.highest_bid {
+				// 只有为出价预留的余额才能用来付款，不足以付清出价时不转移 kitty，不会少付卖家，
+				// 也不会动用出价者的押金
+				Some((winner, price))
+					if T::Currency::reserved_balance_named(&AUCTION_RESERVE_ID, &winner) >= price &&
+						T::Currency::reserved_balance(&winner) >= price &&
+						Self::move_kitty(&auction.seller, &winner, kitty_id).is_ok() =>
+				{
+					match T::Currency::repatriate_reserved_named(
+						&AUCTION_RESERVE_ID,
+						&winner,
+						&auction.seller,
+						price,
+						BalanceStatus::Free,
+					) {
+						Ok(missing) if missing.is_zero() => {
+							Self::deposit_event(Event::AuctionSettled {
+								seller: auction.seller,
+								winner,
+								kitty_id,
+								price,
+							});
+						},
+						Ok(missing) => {
+							// 上面检查过预留余额，只有其它模块改动了出价者的预留余额时才会少付。
+							// 卖家已经收到了部分出价，按已经支付的款项成交，kitty 留给出价者
+							T::Currency::unreserve_named(&AUCTION_RESERVE_ID, &winner, missing);
+							Self::deposit_event(Event::AuctionUnderpaid {
+								seller: auction.seller,
+								winner,
+								kitty_id,
+								price,
+								missing,
+							});
+						},
+						Err(_) => {
+							// 没有付款时 kitty 退回卖家并退还出价。卖家刚转出这个 kitty，
+							// 退回不会超出上限
+							let _ = Self::move_kitty(&winner, &auction.seller, kitty_id);
+							T::Currency::unreserve_named(&AUCTION_RESERVE_ID, &winner, price);
+							Self::deposit_event(Event::AuctionExpired {
+								seller: auction.seller,
+								kitty_id,
+							});
+						},
+					}
+				},
+				highest_bid => {
+					// 没有出价，出价者拥有的 kitty 已达上限，或者出价的预留余额不足，退还出价
+					if let Some((bidder, price)) = highest_bid {
+						T::Currency::unreserve_named(&AUCTION_RESERVE_ID, &bidder, price);
+					}
+					Self::deposit_event(Event::AuctionExpired { seller: auction.seller, kitty_id });
+				},
+			}
+		}
+
+		/// 把 kitty 加入所有者的索引
+		fn add_owned_kitty(owner: &T::AccountId, kitty_id: T::KittyId) -> DispatchResult {
+			OwnedKitties::<T>::try_mutate(owner, |owned| owned.try_push(kitty_id))
+				.map_err(|_| Error::<T>::TooManyKitties.into())
+		}
+
+		/// 把 kitty 从所有者的索引中移除
+		fn remove_owned_kitty(owner: &T::AccountId, kitty_id: T::KittyId) {
+			OwnedKitties::<T>::mutate_exists(owner, |owned| {
+				if let Some(kitties) = owned {
+					kitties.retain(|id| *id != kitty_id);
+					if kitties.is_empty() {
+						*owned = None;
+					}
+				}
+			});
+		}
+
+		/// 更新 kitty 的所有者和双方的索引，押金随 kitty 转给新的所有者
+		fn move_kitty(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			kitty_id: T::KittyId,
+		) -> DispatchResult {
+			if from == to {
+				return Ok(())
+			}
+
+			// 先检查再修改，保证在 on_initialize 中失败时不会留下部分修改
+			ensure!(
+				(Self::owned_kitties(to).len() as u32) < T::MaxKittiesOwned::get(),
+				Error::<T>::TooManyKitties
+			);
+
+			let deposit = KittyDeposits::<T>::get(kitty_id);
+			if !deposit.is_zero() {
+				let missing =
+					T::Currency::repatriate_reserved(from, to, deposit, BalanceStatus::Reserved)?;
+				KittyDeposits::<T>::insert(kitty_id, deposit.saturating_sub(missing));
+			}
+
+			Self::add_owned_kitty(to, kitty_id)?;
+			Self::remove_owned_kitty(from, kitty_id);
+			KittyOwner::<T>::insert(kitty_id, to);
+			// 换了所有者之后原来的配种报价、出借报价和授权失效
+			SiringOffers::<T>::remove(kitty_id);
+			LoanOffers::<T>::remove(kitty_id);
+			KittyApprovals::<T>::remove(kitty_id);
+			Ok(())
+		}
+
+		fn get_account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+	}
+}
+
+/// Decode a kitty name. Names may be padded with trailing zero bytes, the rest has to be
+/// non-empty printable UTF-8.
+pub fn decode_name(name: &[u8; 8]) -> Option<&str> {
+	let len = name.iter().rposition(|byte| *byte != 0).map_or(0, |i| i + 1);
+	let name = sp_std::str::from_utf8(&name[..len]).ok()?;
+	if name.is_empty() || name.chars().any(char::is_control) {
+		return None
 	}
+	Some(name)
 }
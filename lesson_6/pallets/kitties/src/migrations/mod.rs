@@ -1,2 +1,131 @@
-// pub mod v1;
+//! Storage migrations of the kitties pallet.
+//!
+//! Every `vN` module holds a [`MigrationStep`] that moves storage from version `N - 1` to `N`.
+//! The steps are wrapped in [`VersionedMigration`] and chained in [`Migrations`], which the
+//! runtime runs on upgrade. A step migrates at most `T::MigrationBatchSize` items per block:
+//! when a map is larger than that, the step saves a cursor in `MigrationCursor`, leaves the
+//! storage version untouched and the pallet resumes the chain in `on_initialize` until every
+//! step has run.
+
+use frame_support::{
+	pallet_prelude::*,
+	storage::PrefixIterator,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{Config, MigrationCursor, Pallet};
+
+pub mod v1;
 pub mod v2;
+pub mod v3;
+pub mod v4;
+pub mod v5;
+pub mod v6;
+pub mod v7;
+pub mod v8;
+
+/// The raw storage key of the last migrated item.
+pub type Cursor = BoundedVec<u8, ConstU32<128>>;
+
+/// All migrations of the pallet, in version order.
+pub type Migrations<T> = (
+	VersionedMigration<T, v1::MigrateToV1<T>>,
+	VersionedMigration<T, v2::MigrateToV2<T>>,
+	VersionedMigration<T, v3::MigrateToV3<T>>,
+	VersionedMigration<T, v4::MigrateToV4<T>>,
+	VersionedMigration<T, v5::MigrateToV5<T>>,
+	VersionedMigration<T, v6::MigrateToV6<T>>,
+	VersionedMigration<T, v7::MigrateToV7<T>>,
+	VersionedMigration<T, v8::MigrateToV8<T>>,
+);
+
+/// A migration from storage version `VERSION - 1` to `VERSION` that can be split across blocks.
+pub trait MigrationStep {
+	/// The storage version after this step.
+	const VERSION: u16;
+
+	/// Migrate at most `limit` items, starting after `cursor`. Returns the cursor to resume from,
+	/// `None` once every item has been migrated, and the weight used.
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight);
+
+	/// Record the state that [`MigrationStep::post_upgrade`] checks against.
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok(Vec::new())
+	}
+
+	/// Check the storage once the step has migrated every item.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		Ok(())
+	}
+}
+
+/// Runs the step `S` when the on-chain storage version is `S::VERSION - 1`, and bumps the version
+/// once the step has finished.
+pub struct VersionedMigration<T, S>(PhantomData<(T, S)>);
+
+impl<T: Config, S: MigrationStep> VersionedMigration<T, S> {
+	fn should_run() -> bool {
+		Pallet::<T>::on_chain_storage_version() == StorageVersion::new(S::VERSION - 1)
+	}
+}
+
+impl<T: Config, S: MigrationStep> OnRuntimeUpgrade for VersionedMigration<T, S> {
+	fn on_runtime_upgrade() -> Weight {
+		if !Self::should_run() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let cursor = MigrationCursor::<T>::take();
+		let (cursor, weight) = S::migrate(cursor, T::MigrationBatchSize::get().max(1));
+		match cursor {
+			Some(cursor) => MigrationCursor::<T>::put(cursor),
+			None => StorageVersion::new(S::VERSION).put::<Pallet<T>>(),
+		}
+
+		weight.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let state = if Self::should_run() { Some(S::pre_upgrade()?) } else { None };
+		Ok(state.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let state = Option::<Vec<u8>>::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the pre_upgrade state")?;
+
+		// 还在分块迁移时无法检查，等迁移完成后才能检查
+		match state {
+			Some(state) if Pallet::<T>::on_chain_storage_version() >= S::VERSION =>
+				S::post_upgrade(state),
+			_ => Ok(()),
+		}
+	}
+}
+
+/// Call `f` on at most `limit` items of `iter`. Returns the cursor after the last visited item
+/// if the limit was reached, and how many items were visited.
+pub(crate) fn process_batch<Item>(
+	mut iter: PrefixIterator<Item>,
+	limit: u32,
+	mut f: impl FnMut(Item),
+) -> (Option<Cursor>, u64) {
+	let mut count = 0u64;
+	while count < limit as u64 {
+		match iter.next() {
+			Some(item) => f(item),
+			None => return (None, count),
+		}
+		count += 1;
+	}
+
+	// kitty 相关的存储键都远短于游标的长度上限
+	(Some(Cursor::truncate_from(iter.last_raw_key().to_vec())), count)
+}
@@ -1,37 +1,61 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{
-	migration::storage_key_iter, storage::StoragePrefixedMap,
-	traits::GetStorageVersion, weights::Weight, Blake2_128Concat,
-};
+use frame_support::{pallet_prelude::*, storage_alias};
 use scale_info::TypeInfo;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
 
-use crate::{Config, Kitties, KittyId, Pallet};
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{Config, Pallet};
 
+/// The kitty layout before version 1.
 #[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct OldKitty(pub [u8; 16]);
 
-pub fn migrate<T: Config>() -> Weight {
-	let on_chain_version = Pallet::<T>::on_chain_storage_version();
-	let current_version = Pallet::<T>::current_storage_version();
+/// The kitty layout of version 1.
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Kitty {
+	pub dna: [u8; 16],
+	pub name: [u8; 4],
+}
 
-	if on_chain_version != 0 {
-		return Weight::zero()
-	}
+pub mod v0 {
+	use super::*;
 
-	if current_version != 1 {
-		return Weight::zero()
-	}
+	#[storage_alias]
+	pub type Kitties<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, T::KittyId, OldKitty>;
+}
+
+#[storage_alias]
+pub type Kitties<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, T::KittyId, Kitty>;
 
-	let module = Kitties::<T>::module_prefix();
-	let item = Kitties::<T>::storage_prefix();
+/// 给 kitty 加上名字
+pub struct MigrateToV1<T>(PhantomData<T>);
 
-	for (index, kitty) in storage_key_iter::<KittyId, OldKitty, Blake2_128Concat>(module, item).drain() {
-		let new_kitty = crate::Kitty {
-			dna: kitty.0,
-			name: *b"None",
+impl<T: Config> MigrationStep for MigrateToV1<T> {
+	const VERSION: u16 = 1;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => v0::Kitties::<T>::iter_from(cursor.into_inner()),
+			None => v0::Kitties::<T>::iter(),
 		};
-		Kitties::<T>::insert(index, new_kitty);
+		let (cursor, count) = process_batch(iter, limit, |(kitty_id, kitty)| {
+			Kitties::<T>::insert(kitty_id, Kitty { dna: kitty.0, name: *b"None" });
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count + 1, count))
 	}
 
-	todo!()
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((v0::Kitties::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the kitty count")?;
+		ensure!(Kitties::<T>::iter().count() as u32 == count, "Kitties were lost in the migration");
+		Ok(())
+	}
 }
@@ -1,41 +1,40 @@
-use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{
-	migration::storage_key_iter, storage::StoragePrefixedMap,
-	traits::GetStorageVersion, weights::Weight, Blake2_128Concat,
-};
-use scale_info::TypeInfo;
-
-use crate::{Config, Kitties, KittyId, Pallet};
-
-#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
-pub struct OldKitty {
-	dna: [u8; 16],
-	name: [u8; 4],
-}
-
-pub fn migrate<T: Config>() -> Weight {
-	let on_chain_version = Pallet::<T>::on_chain_storage_version();
-	let current_version = Pallet::<T>::current_storage_version();
-
-	if on_chain_version != 1 {
-		return Weight::zero()
+use frame_support::pallet_prelude::*;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::{process_batch, v1, Cursor, MigrationStep};
+use crate::{Config, Kitties, Kitty};
+
+/// 把名字扩展到 8 个字节
+pub struct MigrateToV2<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV2<T> {
+	const VERSION: u16 = 2;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => v1::Kitties::<T>::iter_from(cursor.into_inner()),
+			None => v1::Kitties::<T>::iter(),
+		};
+		let (cursor, count) = process_batch(iter, limit, |(kitty_id, kitty)| {
+			let mut name = [0u8; 8];
+			name[..4].copy_from_slice(&kitty.name);
+			Kitties::<T>::insert(kitty_id, Kitty { dna: kitty.dna, name });
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count + 1, count))
 	}
 
-	if current_version != 2 {
-		return Weight::zero()
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((v1::Kitties::<T>::iter_keys().count() as u32).encode())
 	}
 
-	let module = Kitties::<T>::module_prefix();
-	let item = Kitties::<T>::storage_prefix();
-
-	for (index, kitty) in
-		storage_key_iter::<KittyId, OldKitty, Blake2_128Concat>(module, item).drain()
-	{
-		let mut name = [0u8; 8];
-		name[0..4].copy_from_slice(&kitty.name[..4]);
-		let new_kitty = crate::Kitty { dna: kitty.dna, name};
-		Kitties::<T>::insert(index, &new_kitty);
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the kitty count")?;
+		ensure!(Kitties::<T>::iter().count() as u32 == count, "Kitties were lost in the migration");
+		Ok(())
 	}
-
-	Weight::zero()
 }
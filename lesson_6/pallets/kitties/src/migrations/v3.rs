@@ -0,0 +1,49 @@
+use frame_support::{pallet_prelude::*, storage_alias};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{Config, KittyOnSale, Pallet};
+
+pub mod v2 {
+	use super::*;
+
+	#[storage_alias]
+	pub type KittyOnSale<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, T::KittyId, ()>;
+}
+
+/// 之前上架的 kitty 都按固定的 KittyPrice 出售
+pub struct MigrateToV3<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV3<T> {
+	const VERSION: u16 = 3;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => v2::KittyOnSale::<T>::iter_from(cursor.into_inner()),
+			None => v2::KittyOnSale::<T>::iter(),
+		};
+		let price = T::KittyPrice::get();
+		let (cursor, count) = process_batch(iter, limit, |(kitty_id, _)| {
+			KittyOnSale::<T>::insert(kitty_id, price);
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count + 1, count))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((v2::KittyOnSale::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the listing count")?;
+		ensure!(
+			KittyOnSale::<T>::iter().count() as u32 == count,
+			"Listings were lost in the migration"
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,53 @@
+use frame_support::pallet_prelude::*;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{Config, KittyOwner, OwnedKitties};
+
+/// 根据已有的 KittyOwner 建立所有者索引。超出上限的 kitty 不会被索引，迁移会记录警告，
+/// try-runtime 检查会失败
+pub struct MigrateToV4<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV4<T> {
+	const VERSION: u16 = 4;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => KittyOwner::<T>::iter_from(cursor.into_inner()),
+			None => KittyOwner::<T>::iter(),
+		};
+		let (cursor, count) = process_batch(iter, limit, |(kitty_id, owner)| {
+			OwnedKitties::<T>::mutate(&owner, |owned| {
+				// 超出上限的 kitty 只能通过 KittyOwner 查到，需要先调高 MaxKittiesOwned 再迁移
+				if owned.try_push(kitty_id).is_err() {
+					log::warn!(
+						target: "runtime::kitties",
+						"kitty {:?} of {:?} exceeds MaxKittiesOwned and is not indexed",
+						kitty_id,
+						owner,
+					);
+				}
+			});
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count * 2 + 1, count))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		// 每个 kitty 都应该被索引
+		let expected = KittyOwner::<T>::iter_values().count() as u32;
+		Ok(expected.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let expected =
+			u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the kitty count")?;
+		let indexed: u32 = OwnedKitties::<T>::iter_values().map(|owned| owned.len() as u32).sum();
+		ensure!(indexed == expected, "Owned kitties were not indexed, raise MaxKittiesOwned first");
+		Ok(())
+	}
+}
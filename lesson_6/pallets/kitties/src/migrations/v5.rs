@@ -0,0 +1,56 @@
+use frame_support::pallet_prelude::*;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{Config, Kitties, KittyByName};
+
+/// 为已有的 kitty 建立名字索引，重名时只索引遍历到的第一个，其余的记录在日志中
+pub struct MigrateToV5<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV5<T> {
+	const VERSION: u16 = 5;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		// `UniqueNames` 可能保存在存储中，读取它也要计入权重
+		if !T::UniqueNames::get() {
+			return (None, T::DbWeight::get().reads(1))
+		}
+
+		let iter = match cursor {
+			Some(cursor) => Kitties::<T>::iter_from(cursor.into_inner()),
+			None => Kitties::<T>::iter(),
+		};
+		let (cursor, count) = process_batch(iter, limit, |(kitty_id, kitty)| {
+			match KittyByName::<T>::get(kitty.name) {
+				None => KittyByName::<T>::insert(kitty.name, kitty_id),
+				// 重名的 kitty 不能通过名字查到，需要所有者改名
+				Some(indexed) => log::warn!(
+					target: "runtime::kitties",
+					"kitty {:?} is not indexed, its name is taken by kitty {:?}",
+					kitty_id,
+					indexed,
+				),
+			}
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count * 2 + 2, count))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let names: BTreeSet<[u8; 8]> = Kitties::<T>::iter_values().map(|kitty| kitty.name).collect();
+		Ok((names.len() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		if !T::UniqueNames::get() {
+			return Ok(())
+		}
+		let names = u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the name count")?;
+		ensure!(KittyByName::<T>::iter_keys().count() as u32 == names, "Names were not indexed");
+		Ok(())
+	}
+}
@@ -0,0 +1,51 @@
+use frame_support::pallet_prelude::*;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{Config, FreeKittyIds, KittyChildren, KittyParents, Pallet};
+
+/// 统计每个 kitty 的子代数量，并从空闲列表中移除还被引用的 id
+pub struct MigrateToV6<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV6<T> {
+	const VERSION: u16 = 6;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => KittyParents::<T>::iter_from(cursor.into_inner()),
+			None => KittyParents::<T>::iter(),
+		};
+		let (cursor, count) = process_batch(iter, limit, |(_, (parent_1, parent_2))| {
+			for parent_id in [parent_1, parent_2] {
+				KittyChildren::<T>::mutate(parent_id, |children| {
+					*children = children.saturating_add(1)
+				});
+			}
+		});
+		let mut weight = T::DbWeight::get().reads_writes(count * 3 + 1, count * 2);
+
+		// 所有子代都统计完以后才能知道哪些空闲的 id 还被引用
+		if cursor.is_none() {
+			let mut len = 0u64;
+			FreeKittyIds::<T>::mutate(|free| {
+				len = free.len() as u64;
+				free.retain(|kitty_id| !Pallet::<T>::is_kitty_id_referenced(*kitty_id));
+			});
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(len * 3 + 1, 1));
+		}
+
+		(cursor, weight)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		let free = FreeKittyIds::<T>::get();
+		ensure!(
+			free.iter().all(|kitty_id| !Pallet::<T>::is_kitty_id_referenced(*kitty_id)),
+			"A free kitty id is still referenced"
+		);
+		Ok(())
+	}
+}
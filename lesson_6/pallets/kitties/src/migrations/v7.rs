@@ -0,0 +1,34 @@
+use frame_support::{
+	pallet_prelude::*,
+	traits::{NamedReservableCurrency, ReservableCurrency},
+};
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{Auctions, Config, AUCTION_RESERVE_ID};
+
+/// 把进行中的拍卖的最高出价从普通预留改为 `AUCTION_RESERVE_ID` 下的预留
+pub struct MigrateToV7<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV7<T> {
+	const VERSION: u16 = 7;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => Auctions::<T>::iter_from(cursor.into_inner()),
+			None => Auctions::<T>::iter(),
+		};
+		let mut bids = 0u64;
+		let (cursor, count) = process_batch(iter, limit, |(_, auction)| {
+			if let Some((bidder, amount)) = auction.highest_bid {
+				// 只转换实际还预留着的部分，预留失败时出价变为可用余额，结算时按出价不足处理
+				let moved = amount.saturating_sub(T::Currency::unreserve(&bidder, amount));
+				let _ = T::Currency::reserve_named(&AUCTION_RESERVE_ID, &bidder, moved);
+				bids += 1;
+			}
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count + bids * 2 + 1, bids * 2))
+	}
+}
@@ -0,0 +1,39 @@
+use frame_support::pallet_prelude::*;
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use super::{process_batch, Cursor, MigrationStep};
+use crate::{BreedCommitments, CommittedKitties, Config};
+
+/// 为等待揭示的繁殖承诺建立父母到提交账户的索引
+pub struct MigrateToV8<T>(PhantomData<T>);
+
+impl<T: Config> MigrationStep for MigrateToV8<T> {
+	const VERSION: u16 = 8;
+
+	fn migrate(cursor: Option<Cursor>, limit: u32) -> (Option<Cursor>, Weight) {
+		let iter = match cursor {
+			Some(cursor) => BreedCommitments::<T>::iter_from(cursor.into_inner()),
+			None => BreedCommitments::<T>::iter(),
+		};
+		let (cursor, count) = process_batch(iter, limit, |(who, commitment)| {
+			CommittedKitties::<T>::insert(commitment.kitty_id_1, &who);
+			CommittedKitties::<T>::insert(commitment.kitty_id_2, &who);
+		});
+
+		(cursor, T::DbWeight::get().reads_writes(count + 1, count * 2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			BreedCommitments::<T>::iter().all(|(who, commitment)| {
+				CommittedKitties::<T>::get(commitment.kitty_id_1) == Some(who.clone()) &&
+					CommittedKitties::<T>::get(commitment.kitty_id_2) == Some(who)
+			}),
+			"A breed commitment was not indexed"
+		);
+		Ok(())
+	}
+}
@@ -1,15 +1,18 @@
 use crate as pallet_kitties;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64},
 	PalletId,
 };
+use frame_system::{EnsureNever, EnsureRoot};
+use pallet_kitties::randomness::RandomnessOrElse;
 use pallet_balances::{self, AccountData};
 use pallet_insecure_randomness_collective_flip;
 use sp_core::{ConstU128, ConstU32, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -26,6 +29,7 @@ frame_support::construct_runtime!(
 		KittiesModule: pallet_kitties,
 		Randomness: pallet_insecure_randomness_collective_flip,
 		Balances: pallet_balances,
+		KittyShares: pallet_assets,
 	}
 );
 
@@ -62,7 +66,7 @@ pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
 impl pallet_balances::Config for Test {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -74,17 +78,84 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Test>;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	// 份额资产只能由 kitties pallet 创建
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub const MAX_AUCTION_DURATION: u64 = 100;
+pub const MAX_LOAN_DURATION: u64 = 100;
+pub const MAX_KITTIES_OWNED: u32 = 8;
+pub const BREED_COOLDOWN_BASE: u64 = 10;
+pub const MAX_BREED_COOLDOWN: u64 = 40;
+pub const BREED_REVEAL_WINDOW: u32 = 5;
+pub const MIGRATION_BATCH_SIZE: u32 = 3;
+pub const MAX_RECYCLED_KITTY_IDS: u32 = 2;
+pub const MAX_BUYOUT_VOTERS: u32 = 2;
+pub const BUYOUT_OFFER_DURATION: u64 = 10;
+
 parameter_types! {
 	pub KittyPalletId: PalletId = PalletId(*b"py/kitty");
 	pub KittyPrice: Balance = EXISTENTIAL_DEPOSIT * 10;
+	pub MutationRate: Perbill = Perbill::from_percent(5);
+	pub BuyoutThreshold: Perbill = Perbill::from_percent(50);
+	// 上一个区块的 VRF 输出，为 None 时使用父区块哈希生成的随机数
+	pub storage VrfOutput: Option<H256> = None;
+	pub storage UniqueNames: bool = false;
+}
+
+// 模拟 pallet_babe::ParentBlockRandomness
+pub struct ParentBlockVrfRandomness;
+impl frame_support::traits::Randomness<Option<H256>, u64> for ParentBlockVrfRandomness {
+	fn random(subject: &[u8]) -> (Option<H256>, u64) {
+		let output = VrfOutput::get().map(|vrf| BlakeTwo256::hash_of(&(subject, vrf)));
+		(output, System::block_number().saturating_sub(1))
+	}
 }
 
 impl pallet_kitties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type Randomness = Randomness;
+	type KittyId = u32;
+	type Randomness = RandomnessOrElse<ParentBlockVrfRandomness, Randomness>;
 	type Currency = Balances;
 	type KittyPrice = KittyPrice;
 	type PalletId = KittyPalletId;
+	type MaxAuctionDuration = ConstU64<MAX_AUCTION_DURATION>;
+	type MaxAuctionsPerBlock = ConstU32<2>;
+	type MaxLoanDuration = ConstU64<MAX_LOAN_DURATION>;
+	type MaxLoansPerBlock = ConstU32<2>;
+	type MaxKittiesOwned = ConstU32<MAX_KITTIES_OWNED>;
+	type MutationRate = MutationRate;
+	type BreedCooldownBase = ConstU64<BREED_COOLDOWN_BASE>;
+	type MaxBreedCooldown = ConstU64<MAX_BREED_COOLDOWN>;
+	type BreedRevealWindow = ConstU32<BREED_REVEAL_WINDOW>;
+	type UniqueNames = UniqueNames;
+	type MigrationBatchSize = ConstU32<MIGRATION_BATCH_SIZE>;
+	type MaxRecycledKittyIds = ConstU32<MAX_RECYCLED_KITTY_IDS>;
+	type ShareAssets = KittyShares;
+	type BuyoutThreshold = BuyoutThreshold;
+	type BuyoutOfferDuration = ConstU64<BUYOUT_OFFER_DURATION>;
+	type MaxBuyoutVoters = ConstU32<MAX_BUYOUT_VOTERS>;
+	type WeightInfo = ();
 }
 
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
@@ -0,0 +1,54 @@
+//! Implementations of the single-collection `nonfungible` traits, so that other pallets can
+//! inspect, transfer and burn kitties without depending on this pallet directly.
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::tokens::nonfungible::{Inspect, Mutate, Transfer},
+};
+use sp_std::vec::Vec;
+
+use crate::{Auctions, Config, Error, Fractions, Kitties, KittyLoans, KittyOwner, Pallet};
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type ItemId = T::KittyId;
+
+	fn owner(item: &T::KittyId) -> Option<T::AccountId> {
+		KittyOwner::<T>::get(item)
+	}
+
+	/// The kitty's `dna` and `name` are exposed as attributes.
+	fn attribute(item: &T::KittyId, key: &[u8]) -> Option<Vec<u8>> {
+		let kitty = Kitties::<T>::get(item)?;
+		match key {
+			b"dna" => Some(kitty.dna.to_vec()),
+			b"name" => Some(kitty.name.to_vec()),
+			_ => None,
+		}
+	}
+
+	fn can_transfer(item: &T::KittyId) -> bool {
+		KittyOwner::<T>::contains_key(item) &&
+			!Auctions::<T>::contains_key(item) &&
+			!KittyLoans::<T>::contains_key(item) &&
+			!Fractions::<T>::contains_key(item)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(item: &T::KittyId, destination: &T::AccountId) -> DispatchResult {
+		let owner = KittyOwner::<T>::get(item).ok_or(Error::<T>::InvalidKittyId)?;
+		Self::do_transfer(owner, destination.clone(), *item)
+	}
+}
+
+// kitty 只能通过 create 和 breed 生成，这里不支持 mint_into
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	fn burn(item: &T::KittyId, maybe_check_owner: Option<&T::AccountId>) -> DispatchResult {
+		let owner = KittyOwner::<T>::get(item).ok_or(Error::<T>::InvalidKittyId)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(*check_owner == owner, Error::<T>::NotOwner);
+		}
+		Self::do_burn(owner, *item)
+	}
+}
@@ -0,0 +1,31 @@
+//! Randomness sources for breeding.
+
+use frame_support::traits::Randomness;
+use sp_std::marker::PhantomData;
+
+/// Uses the randomness from `R` when it is available and falls back to `F` otherwise.
+///
+/// This lets the pallet use BABE's per-block VRF output, `pallet_babe::ParentBlockRandomness`,
+/// which is `None` when the parent block was not authored in a primary VRF slot, with BABE's
+/// per-epoch randomness as the fallback:
+///
+/// ```ignore
+/// type Randomness = RandomnessOrElse<
+/// 	pallet_babe::ParentBlockRandomness<Runtime>,
+/// 	pallet_babe::RandomnessFromOneEpochAgo<Runtime>,
+/// >;
+/// ```
+pub struct RandomnessOrElse<R, F>(PhantomData<(R, F)>);
+
+impl<Output, BlockNumber, R, F> Randomness<Output, BlockNumber> for RandomnessOrElse<R, F>
+where
+	R: Randomness<Option<Output>, BlockNumber>,
+	F: Randomness<Output, BlockNumber>,
+{
+	fn random(subject: &[u8]) -> (Output, BlockNumber) {
+		match R::random(subject) {
+			(Some(output), known_since) => (output, known_since),
+			(None, _) => F::random(subject),
+		}
+	}
+}
@@ -1,7 +1,25 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	genome::{self, Colour, EyeShape, Genome, Pattern, Rarity},
+	migrations::{v1, v3, v4, v5, v6, v7, v8, Migrations, VersionedMigration},
+	mock::*,
+	AuctionKind, BreedingInfo, BuyoutClaim, Error, Event, UnseededBreedBlocks, AUCTION_RESERVE_ID,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	traits::{
+		tokens::nonfungible, GetStorageVersion, Hooks, NamedReservableCurrency, OnRuntimeUpgrade,
+		ReservableCurrency, StorageVersion,
+	},
+	BoundedVec,
+};
 use once_cell::sync::Lazy;
-use sp_runtime::traits::AccountIdConversion;
+use proptest::prelude::*;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256, Hash},
+	Perbill,
+};
 
 const KITTY_ID: u32 = 0;
 const KITTY_NAME: [u8; 8] = *b"test0000";
@@ -9,12 +27,20 @@ const ACCOUNT_ID: u64 = 1;
 const ACCOUNT_ID2: u64 = 2;
 const ACCOUNT_BALANCE: u128 = 100000;
 const ACCOUNT_BALANCE2: u128 = 100000;
+const ACCOUNT_ID3: u64 = 3;
 
 static PALLET_ACCOUNT_ID: Lazy<u64> = Lazy::new(|| {
 	KittyPalletId::get().into_account_truncating()
 });
 const PALLET_BALANCE: u128 = 0;
+const KITTY_PRICE: u128 = 2000;
 
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		KittiesModule::on_initialize(System::block_number());
+	}
+}
 
 #[test]
 fn it_works_for_create() {
@@ -27,10 +53,13 @@ fn it_works_for_create() {
 		assert_eq!(KittiesModule::next_kitty_id(), KITTY_ID + 1);
 		assert_eq!(Balances::free_balance(ACCOUNT_ID), ACCOUNT_BALANCE - EXISTENTIAL_DEPOSIT * 10);
 		assert_eq!(
-			Balances::free_balance(*PALLET_ACCOUNT_ID),
-			PALLET_BALANCE + EXISTENTIAL_DEPOSIT * 10
+			Balances::reserved_balance(ACCOUNT_ID),
+			EXISTENTIAL_DEPOSIT * 10
 		);
 
+		assert_eq!(KittiesModule::kitty_deposit(KITTY_ID), EXISTENTIAL_DEPOSIT * 10);
+		assert_eq!(Balances::free_balance(*PALLET_ACCOUNT_ID), PALLET_BALANCE);
+
 		assert!(KittiesModule::kitties(KITTY_ID).is_some());
 		assert!(KittiesModule::kitties(KITTY_ID).unwrap().name == KITTY_NAME);
 
@@ -45,10 +74,10 @@ fn it_works_for_create() {
 		);
 
 		// 当 kitty_id 达到阈值，创建失败
-		crate::NextKittyId::<Test>::set(crate::KittyId::max_value());
+		crate::NextKittyId::<Test>::set(u32::MAX);
 		assert_noop!(
 			KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME),
-			Error::<Test>::InvalidKittyId
+			Error::<Test>::KittyIdOverflow
 		);
 	});
 }
@@ -88,8 +117,8 @@ fn it_works_for_breed() {
 			ACCOUNT_BALANCE - 2 * EXISTENTIAL_DEPOSIT * 10
 		);
 		assert_eq!(
-			Balances::free_balance(*PALLET_ACCOUNT_ID),
-			PALLET_BALANCE + 2 * EXISTENTIAL_DEPOSIT * 10
+			Balances::reserved_balance(ACCOUNT_ID),
+			2 * EXISTENTIAL_DEPOSIT * 10
 		);
 
 		assert_ok!(KittiesModule::breed(
@@ -103,8 +132,8 @@ fn it_works_for_breed() {
 			ACCOUNT_BALANCE - 3 * EXISTENTIAL_DEPOSIT * 10
 		);
 		assert_eq!(
-			Balances::free_balance(*PALLET_ACCOUNT_ID),
-			PALLET_BALANCE + 3 * EXISTENTIAL_DEPOSIT * 10
+			Balances::reserved_balance(ACCOUNT_ID),
+			3 * EXISTENTIAL_DEPOSIT * 10
 		);
 
 		let breed_kitty_id = 2;
@@ -125,6 +154,7 @@ fn it_works_for_transfer() {
 	new_test_ext().execute_with(|| {
 		// 账号充值
 		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
 
 		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
 		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
@@ -136,6 +166,10 @@ fn it_works_for_transfer() {
 
 		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID));
 		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID2));
+
+		// 押金随 kitty 一起转移
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), EXISTENTIAL_DEPOSIT * 10);
 		System::assert_last_event(
 			Event::KittyTransferred { who: ACCOUNT_ID, recipient: ACCOUNT_ID2, kitty_id: 0 }.into(),
 		);
@@ -155,30 +189,32 @@ fn it_works_for_sale() {
 
 		// 当不存在 kitty 时失败
 		assert_noop!(
-			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
 			Error::<Test>::InvalidKittyId
 		);
 
 		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
 		assert_eq!(Balances::free_balance(ACCOUNT_ID), ACCOUNT_BALANCE - EXISTENTIAL_DEPOSIT * 10);
 		assert_eq!(
-			Balances::free_balance(*PALLET_ACCOUNT_ID),
-			PALLET_BALANCE + EXISTENTIAL_DEPOSIT * 10
+			Balances::reserved_balance(ACCOUNT_ID),
+			EXISTENTIAL_DEPOSIT * 10
 		);
 		// 当所有者不正确时失败
 		assert_noop!(
-			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE),
 			Error::<Test>::NotOwner
 		);
 
 		// 所有者正确，成功
-		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
-		assert!(KittiesModule::kitty_on_sale(KITTY_ID).is_some());
-		System::assert_last_event(Event::KittyOnSale { who: ACCOUNT_ID, kitty_id: 0 }.into());
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+		assert_eq!(KittiesModule::kitty_on_sale(KITTY_ID), Some(KITTY_PRICE));
+		System::assert_last_event(
+			Event::KittyOnSale { who: ACCOUNT_ID, kitty_id: 0, price: KITTY_PRICE }.into(),
+		);
 
 		// 重复 sale, 失败
 		assert_noop!(
-			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
 			Error::<Test>::AlreadyOnSale
 		);
 	});
@@ -193,7 +229,7 @@ fn it_works_for_buy() {
 
 		// 当不存在 kitty 时失败
 		assert_noop!(
-			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
 			Error::<Test>::InvalidKittyId
 		);
 
@@ -202,23 +238,2164 @@ fn it_works_for_buy() {
 
 		// 当购买者与所有者相同时失败
 		assert_noop!(
-			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
 			Error::<Test>::AlreadyOwned
 		);
 
 		// 当没有上架时，失败
 		assert_noop!(
-			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE),
 			Error::<Test>::NotOnSale
 		);
 
+		// 价格高于买家可接受的最高价时失败
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+		assert_noop!(
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE - 1),
+			Error::<Test>::PriceTooHigh
+		);
+
 		// 上述失败条件不存在时，成功
-		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
-		assert_ok!(KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_ok!(KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE));
+		assert!(KittiesModule::kitty_on_sale(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID2));
+		assert_eq!(
+			Balances::free_balance(ACCOUNT_ID),
+			ACCOUNT_BALANCE - EXISTENTIAL_DEPOSIT * 10 + KITTY_PRICE
+		);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), ACCOUNT_BALANCE2 - KITTY_PRICE);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), EXISTENTIAL_DEPOSIT * 10);
+		System::assert_last_event(
+			Event::KittyBought { who: ACCOUNT_ID2, seller: ACCOUNT_ID, kitty_id: 0, price: KITTY_PRICE }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn it_works_for_update_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		// 当没有上架时，失败
+		assert_noop!(
+			KittiesModule::update_price(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::NotOnSale
+		);
+
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+
+		// 当所有者不正确时失败
+		assert_noop!(
+			KittiesModule::update_price(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, 1),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::update_price(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE * 2));
+		assert_eq!(KittiesModule::kitty_on_sale(KITTY_ID), Some(KITTY_PRICE * 2));
+		System::assert_last_event(
+			Event::KittyPriceUpdated { who: ACCOUNT_ID, kitty_id: KITTY_ID, price: KITTY_PRICE * 2 }
+				.into(),
+		);
+
+		// 涨价后按原价购买失败，按新价格购买成功
+		assert_noop!(
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE * 2));
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), ACCOUNT_BALANCE2 - KITTY_PRICE * 2);
+	});
+}
+
+#[test]
+fn it_works_for_cancel_sale() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		assert_noop!(
+			KittiesModule::cancel_sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::NotOnSale
+		);
+
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+		assert_noop!(
+			KittiesModule::cancel_sale(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(KittiesModule::cancel_sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		assert!(KittiesModule::kitty_on_sale(KITTY_ID).is_none());
+		System::assert_last_event(
+			Event::KittySaleCancelled { who: ACCOUNT_ID, kitty_id: KITTY_ID }.into(),
+		);
+		assert_noop!(
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::NotOnSale
+		);
+	});
+}
+
+#[test]
+fn transfer_cancels_sale() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+
+		// 转移后上架信息失效，避免新所有者的 kitty 被按旧价格买走
+		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID));
 		assert!(KittiesModule::kitty_on_sale(KITTY_ID).is_none());
+	});
+}
+
+#[test]
+fn migrate_to_v3_prices_existing_listings() {
+	use frame_support::{storage_alias, Blake2_128Concat};
+
+	#[storage_alias]
+	type KittyOnSale = StorageMap<KittiesModule, Blake2_128Concat, u32, ()>;
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<KittiesModule>();
+		KittyOnSale::insert(KITTY_ID, ());
+		KittyOnSale::insert(KITTY_ID + 1, ());
+
+		VersionedMigration::<Test, v3::MigrateToV3<Test>>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 3);
+		assert_eq!(KittiesModule::kitty_on_sale(KITTY_ID), Some(KittyPrice::get()));
+		assert_eq!(KittiesModule::kitty_on_sale(KITTY_ID + 1), Some(KittyPrice::get()));
+	});
+}
+
+#[test]
+fn english_auction_settles_to_highest_bidder() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let seller_balance = Balances::free_balance(ACCOUNT_ID);
+
+		let kind = AuctionKind::English { reserve: KITTY_PRICE };
+		assert_noop!(
+			KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, kind, 10),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::create_auction(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				KITTY_ID,
+				kind,
+				MAX_AUCTION_DURATION + 1
+			),
+			Error::<Test>::InvalidAuctionDuration
+		);
+		assert_ok!(KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 10));
+		System::assert_last_event(
+			Event::AuctionCreated { who: ACCOUNT_ID, kitty_id: KITTY_ID, kind, end: 11 }.into(),
+		);
+
+		// 低于保留价或不高于当前最高价的出价失败
+		assert_noop!(
+			KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE - 1),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::AlreadyOwned
+		);
+		assert_ok!(KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), KITTY_PRICE);
+		assert_noop!(
+			KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::BidTooLow
+		);
+
+		// 更高的出价会退还之前的最高出价
+		assert_ok!(KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, KITTY_PRICE + 1));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), KITTY_PRICE + 1);
+
+		run_to_block(10);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
+
+		run_to_block(11);
+		assert!(KittiesModule::auctions(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID3));
+		// 出价支付给卖家，kitty 的押金转给买家
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), EXISTENTIAL_DEPOSIT * 10);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID3), ACCOUNT_BALANCE - KITTY_PRICE - 1);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), seller_balance + KITTY_PRICE + 1);
+		System::assert_last_event(
+			Event::AuctionSettled {
+				seller: ACCOUNT_ID,
+				winner: ACCOUNT_ID3,
+				kitty_id: KITTY_ID,
+				price: KITTY_PRICE + 1,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn english_auction_without_bids_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let kind = AuctionKind::English { reserve: KITTY_PRICE };
+		assert_ok!(KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 5));
+
+		run_to_block(6);
+		assert!(KittiesModule::auctions(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
+		System::assert_last_event(Event::AuctionExpired { seller: ACCOUNT_ID, kitty_id: KITTY_ID }.into());
+
+		// 拍卖结束后不能再出价
+		assert_noop!(
+			KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::AuctionNotExist
+		);
+	});
+}
+
+#[test]
+fn english_auction_with_uncovered_bid_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let seller_balance = Balances::free_balance(ACCOUNT_ID);
+		let kind = AuctionKind::English { reserve: KITTY_PRICE };
+		assert_ok!(KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 5));
+		assert_ok!(KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE));
+
+		// 出价的预留余额被其它模块释放了一部分
+		Balances::unreserve(&ACCOUNT_ID2, 1);
+
+		// 出价付不清时 kitty 留在卖家，剩余的出价退还
+		run_to_block(6);
+		assert!(KittiesModule::auctions(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), seller_balance);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), 0);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), ACCOUNT_BALANCE2);
+		System::assert_last_event(Event::AuctionExpired { seller: ACCOUNT_ID, kitty_id: KITTY_ID }.into());
+	});
+}
+
+#[test]
+fn english_auction_does_not_pay_with_other_reserves() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		// 出价者自己的 kitty 押金也预留在账户中
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_NAME));
+		let deposit = Balances::reserved_balance(ACCOUNT_ID2);
+		let seller_balance = Balances::free_balance(ACCOUNT_ID);
+		let kind = AuctionKind::English { reserve: KITTY_PRICE };
+		assert_ok!(KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 5));
+		assert_ok!(KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE));
+		assert_eq!(Balances::reserved_balance_named(&AUCTION_RESERVE_ID, &ACCOUNT_ID2), KITTY_PRICE);
+
+		// 出价的预留少了一部分，总的预留余额仍然足够
+		Balances::unreserve_named(&AUCTION_RESERVE_ID, &ACCOUNT_ID2, 1);
+		assert!(Balances::reserved_balance(ACCOUNT_ID2) >= KITTY_PRICE);
+
+		// 押金不会被用来付款，kitty 留在卖家
+		run_to_block(6);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), seller_balance);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), deposit);
+		System::assert_last_event(Event::AuctionExpired { seller: ACCOUNT_ID, kitty_id: KITTY_ID }.into());
+	});
+}
+
+#[test]
+fn dutch_auction_sells_at_decayed_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let seller_balance = Balances::free_balance(ACCOUNT_ID);
+
+		assert_noop!(
+			KittiesModule::create_auction(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				KITTY_ID,
+				AuctionKind::Dutch { start_price: 1000, floor_price: 2000 },
+				10
+			),
+			Error::<Test>::InvalidAuctionPrice
+		);
+		let kind = AuctionKind::Dutch { start_price: 2000, floor_price: 1000 };
+		assert_ok!(KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 10));
+
+		// 经过一半的时间，价格下降一半
+		run_to_block(6);
+		assert_noop!(
+			KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, 1499),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(KittiesModule::bid(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, 1800));
+
+		// 立即按当前价格成交
+		assert!(KittiesModule::auctions(KITTY_ID).is_none());
+		assert!(crate::AuctionsEndingAt::<Test>::get(11).is_empty());
 		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID2));
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), ACCOUNT_BALANCE2 - 1500);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), seller_balance + 1500);
+		System::assert_last_event(
+			Event::AuctionSettled { seller: ACCOUNT_ID, winner: ACCOUNT_ID2, kitty_id: KITTY_ID, price: 1500 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn kitty_in_auction_cannot_be_transferred_or_sold() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let kind = AuctionKind::English { reserve: KITTY_PRICE };
+		assert_ok!(KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 10));
+
+		assert_noop!(
+			KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::KittyInAuction
+		);
+		assert_noop!(
+			KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, kind, 10),
+			Error::<Test>::KittyInAuction
+		);
+	});
+}
+
+#[test]
+fn auctions_per_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		let kind = AuctionKind::English { reserve: KITTY_PRICE };
+		for kitty_id in 0..3 {
+			assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+			if kitty_id < 2 {
+				assert_ok!(KittiesModule::create_auction(
+					RuntimeOrigin::signed(ACCOUNT_ID),
+					kitty_id,
+					kind,
+					10
+				));
+			}
+		}
+
+		// 同一区块结束的拍卖数量达到上限
+		assert_noop!(
+			KittiesModule::create_auction(RuntimeOrigin::signed(ACCOUNT_ID), 2, kind, 10),
+			Error::<Test>::TooManyAuctions
+		);
+	});
+}
+
+#[test]
+fn owned_kitties_index_is_maintained() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, KITTY_NAME));
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID).to_vec(), vec![0, 1, 2]);
+
+		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, 1));
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID).to_vec(), vec![0, 2]);
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID2).to_vec(), vec![1]);
+
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), 0, KITTY_PRICE));
+		assert_ok!(KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID2), 0, KITTY_PRICE));
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID).to_vec(), vec![2]);
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID2).to_vec(), vec![1, 0]);
+
+		// 转移给自己不会重复索引
+		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID, 2));
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID).to_vec(), vec![2]);
+	});
+}
+
+#[test]
+fn owned_kitties_are_limited() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		for _ in 0..MAX_KITTIES_OWNED {
+			assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		}
+
+		assert_noop!(
+			KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME),
+			Error::<Test>::TooManyKitties
+		);
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, KITTY_NAME),
+			Error::<Test>::TooManyKitties
+		);
+
+		// 接收方已达上限时转移和购买失败
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_NAME));
+		let kitty_id = MAX_KITTIES_OWNED;
+		assert_noop!(
+			KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID2), ACCOUNT_ID, kitty_id),
+			Error::<Test>::TooManyKitties
+		);
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID2), kitty_id, KITTY_PRICE));
+		assert_noop!(
+			KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID), kitty_id, KITTY_PRICE),
+			Error::<Test>::TooManyKitties
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v4_builds_owned_kitties() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<KittiesModule>();
+		crate::KittyOwner::<Test>::insert(0, ACCOUNT_ID);
+		crate::KittyOwner::<Test>::insert(1, ACCOUNT_ID2);
+		crate::KittyOwner::<Test>::insert(2, ACCOUNT_ID);
+
+		VersionedMigration::<Test, v4::MigrateToV4<Test>>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 4);
+		let mut owned = KittiesModule::owned_kitties(ACCOUNT_ID).to_vec();
+		owned.sort();
+		assert_eq!(owned, vec![0, 2]);
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID2).to_vec(), vec![1]);
+	});
+}
+
+#[test]
+fn migrate_to_v4_skips_kitties_over_the_limit() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<KittiesModule>();
+		for kitty_id in 0..MAX_KITTIES_OWNED + 1 {
+			crate::KittyOwner::<Test>::insert(kitty_id, ACCOUNT_ID);
+		}
+
+		// 一次最多迁移 MIGRATION_BATCH_SIZE 个 kitty
+		VersionedMigration::<Test, v4::MigrateToV4<Test>>::on_runtime_upgrade();
+		while KittiesModule::migration_in_progress() {
+			VersionedMigration::<Test, v4::MigrateToV4<Test>>::on_runtime_upgrade();
+		}
+
+		// 超出上限的 kitty 不被索引，迁移不会失败
+		assert_eq!(KittiesModule::on_chain_storage_version(), 4);
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID).len() as u32, MAX_KITTIES_OWNED);
+	});
+}
+
+#[test]
+fn it_works_for_burn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+
+		assert_noop!(
+			KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			Error::<Test>::NotOwner
+		);
+
+		// 销毁 kitty 并退还押金
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		assert!(KittiesModule::kitties(KITTY_ID).is_none());
+		assert!(KittiesModule::kitty_owner(KITTY_ID).is_none());
+		assert!(KittiesModule::kitty_on_sale(KITTY_ID).is_none());
+		assert!(KittiesModule::owned_kitties(ACCOUNT_ID).is_empty());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), 0);
 		assert_eq!(Balances::free_balance(ACCOUNT_ID), ACCOUNT_BALANCE);
-		assert_eq!(Balances::free_balance(ACCOUNT_ID2), ACCOUNT_BALANCE2 - EXISTENTIAL_DEPOSIT * 10);
-		System::assert_last_event(Event::KittyBought { who: ACCOUNT_ID2, kitty_id: 0 }.into());
+		System::assert_last_event(
+			Event::KittyBurned { who: ACCOUNT_ID, kitty_id: KITTY_ID, deposit: EXISTENTIAL_DEPOSIT * 10 }
+				.into(),
+		);
+
+		assert_noop!(
+			KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::InvalidKittyId
+		);
+	});
+}
+
+#[test]
+fn burned_kitty_ids_are_reused() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		for _ in 0..4 {
+			assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		}
+
+		// 后销毁的 id 先被使用
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), 1));
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), 0));
+		assert_eq!(KittiesModule::free_kitty_ids().to_vec(), vec![1, 0]);
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		System::assert_has_event(
+			Event::KittyCreated {
+				who: ACCOUNT_ID,
+				kitty_id: 0,
+				kitty: KittiesModule::kitties(0).unwrap(),
+			}
+			.into(),
+		);
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 2, 3, KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_parents(1), Some((2, 3)));
+		assert!(KittiesModule::free_kitty_ids().is_empty());
+		assert_eq!(KittiesModule::next_kitty_id(), 4);
+
+		// 空闲列表满了以后销毁的 id 不再重用
+		for kitty_id in 0..MAX_RECYCLED_KITTY_IDS + 1 {
+			assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), kitty_id));
+		}
+		assert_eq!(KittiesModule::free_kitty_ids().to_vec(), vec![0, 1]);
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert!(KittiesModule::kitties(4).is_some());
+		assert_eq!(KittiesModule::next_kitty_id(), 5);
+	});
+}
+
+#[test]
+fn burned_kitty_ids_are_reused_after_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		crate::NextKittyId::<Test>::set(u32::MAX - 1);
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_noop!(
+			KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME),
+			Error::<Test>::KittyIdOverflow
+		);
+
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), u32::MAX - 1));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_owner(u32::MAX - 1), Some(ACCOUNT_ID));
+		assert_eq!(KittiesModule::next_kitty_id(), u32::MAX);
+	});
+}
+
+#[test]
+fn burned_parent_ids_are_not_reused_while_children_live() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_children(0), 1);
+
+		// 子代还记录着销毁的父母，父母的 id 不能给新的 kitty
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), 0));
+		assert!(KittiesModule::free_kitty_ids().is_empty());
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert!(KittiesModule::kitties(3).is_some());
+		assert_eq!(KittiesModule::kitty_parents(2), Some((0, 1)));
+
+		// 子代销毁后父母的 id 也可以重用
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), 2));
+		assert_eq!(KittiesModule::free_kitty_ids().to_vec(), vec![2, 0]);
+		assert_eq!(KittiesModule::kitty_children(0), 0);
+		assert_eq!(KittiesModule::kitty_children(1), 0);
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_parents(0), None);
+	});
+}
+
+#[test]
+fn bought_out_kitty_ids_are_reused_after_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, 100));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 40));
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, 1000));
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+
+		// 买家销毁 kitty 时还有份额没有兑换，id 暂不重用
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID));
+		assert!(KittiesModule::free_kitty_ids().is_empty());
+
+		assert_ok!(KittiesModule::claim_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_eq!(KittiesModule::free_kitty_ids().to_vec(), vec![KITTY_ID]);
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, 100));
+	});
+}
+
+// id 分配属性测试中的一步：创建一个 kitty，或者销毁第 n 个 kitty
+#[derive(Clone, Debug)]
+enum IdOp {
+	Create,
+	Burn(usize),
+}
+
+fn id_op() -> impl Strategy<Value = IdOp> {
+	prop_oneof![Just(IdOp::Create), any::<usize>().prop_map(IdOp::Burn)]
+}
+
+proptest! {
+	// 与一个简单的模型比较 id 的分配：优先使用最后回收的 id，空闲列表满了就丢弃，
+	// NextKittyId 用完后只能使用回收的 id，并且不会分配正在使用的 id
+	#[test]
+	fn kitty_id_allocator_matches_model(
+		start in prop_oneof![Just(0u32), (u32::MAX - 8)..=u32::MAX],
+		ops in prop::collection::vec(id_op(), 1..48),
+	) {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+			crate::NextKittyId::<Test>::set(start);
+
+			let mut next = Some(start).filter(|id| *id != u32::MAX);
+			let mut free: Vec<u32> = Vec::new();
+			let mut live: Vec<u32> = Vec::new();
+
+			for op in ops {
+				match op {
+					IdOp::Create if live.len() as u32 == MAX_KITTIES_OWNED => {},
+					IdOp::Create => {
+						let expected = free.pop().or_else(|| {
+							let id = next?;
+							next = id.checked_add(1).filter(|id| *id != u32::MAX);
+							Some(id)
+						});
+						let result = KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME);
+						match expected {
+							Some(kitty_id) => {
+								assert_ok!(result);
+								assert!(!live.contains(&kitty_id));
+								assert_eq!(KittiesModule::kitty_owner(kitty_id), Some(ACCOUNT_ID));
+								live.push(kitty_id);
+							},
+							None => assert_eq!(result, Err(Error::<Test>::KittyIdOverflow.into())),
+						}
+					},
+					IdOp::Burn(_) if live.is_empty() => {},
+					IdOp::Burn(n) => {
+						let kitty_id = live.swap_remove(n % live.len());
+						assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), kitty_id));
+						if (free.len() as u32) < MAX_RECYCLED_KITTY_IDS {
+							free.push(kitty_id);
+						}
+					},
+				}
+
+				assert_eq!(KittiesModule::free_kitty_ids().to_vec(), free);
+				let mut owned = KittiesModule::owned_kitties(ACCOUNT_ID).to_vec();
+				owned.sort();
+				let mut expected_owned = live.clone();
+				expected_owned.sort();
+				assert_eq!(owned, expected_owned);
+			}
+		});
+	}
+}
+
+#[test]
+fn it_works_for_sweep_pallet_account() {
+	new_test_ext().execute_with(|| {
+		// 以前创建 kitty 时转入 pallet 账户的资金
+		let swept = EXISTENTIAL_DEPOSIT * 20;
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), *PALLET_ACCOUNT_ID, swept, 0));
+
+		assert_noop!(
+			KittiesModule::sweep_pallet_account(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		// pallet 账户保留存在押金
+		assert_ok!(KittiesModule::sweep_pallet_account(RuntimeOrigin::root(), ACCOUNT_ID));
+		assert_eq!(Balances::free_balance(*PALLET_ACCOUNT_ID), EXISTENTIAL_DEPOSIT);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), swept - EXISTENTIAL_DEPOSIT);
+		System::assert_last_event(
+			Event::PalletAccountSwept { dest: ACCOUNT_ID, amount: swept - EXISTENTIAL_DEPOSIT }.into(),
+		);
+	});
+}
+
+#[test]
+fn genome_decodes_traits_from_dna() {
+	let mut dna = [0u8; 16];
+	dna[0] = 2;
+	dna[2] = 7;
+	dna[4] = 3;
+	dna[6] = 200;
+	assert_eq!(
+		Genome::from_dna(&dna),
+		Genome {
+			colour: Colour::Ginger,
+			pattern: Pattern::Tabby,
+			eye_shape: EyeShape::Wide,
+			rarity: Rarity::Rare,
+		}
+	);
+
+	// 只有显性等位基因表达性状
+	dna[1] = 0;
+	dna[7] = 255;
+	assert_eq!(Genome::from_dna(&dna).colour, Colour::Ginger);
+	assert_eq!(Genome::from_dna(&dna).rarity, Rarity::Rare);
+}
+
+#[test]
+fn breed_dna_inherits_one_allele_from_each_parent() {
+	let dna_1 = [1, 5, 1, 4, 1, 2, 130, 250, 1, 1, 1, 1, 1, 1, 1, 1];
+	let dna_2 = [3, 6, 2, 5, 0, 3, 10, 255, 2, 2, 2, 2, 2, 2, 2, 2];
+	// 父本取第一个等位基因, 母本取第二个, 剩余字节全部来自母本
+	let mut seed = [0u8; genome::SEED_LENGTH];
+	seed[0..4].copy_from_slice(&[0b10, 0b10, 0b10, 0b10]);
+	seed[28] = 0xff;
+
+	let dna = genome::breed_dna(&dna_1, &dna_2, &seed, Perbill::zero());
+	// 显性 (等级较低) 的等位基因排在前面
+	assert_eq!(&dna[..8], &[1, 6, 1, 5, 1, 3, 130, 255]);
+	assert_eq!(&dna[8..], &dna_2[8..]);
+	assert_eq!(
+		Genome::from_dna(&dna),
+		Genome {
+			colour: Colour::White,
+			pattern: Pattern::Tabby,
+			eye_shape: EyeShape::Almond,
+			rarity: Rarity::Uncommon,
+		}
+	);
+
+	// 隐性性状在两个等位基因都是隐性时才表达
+	seed[0..4].copy_from_slice(&[0b11, 0, 0, 0]);
+	seed[28] = 0;
+	let dna = genome::breed_dna(&dna_1, &dna_2, &seed, Perbill::zero());
+	assert_eq!(&dna[..2], &[5, 6]);
+	assert_eq!(Genome::from_dna(&dna).colour, Colour::Blue);
+	assert_eq!(&dna[8..], &dna_1[8..]);
+}
+
+#[test]
+fn breed_dna_mutates_alleles() {
+	let dna_1 = [0u8; 16];
+	let dna_2 = [0u8; 16];
+	let mut seed = [0u8; genome::SEED_LENGTH];
+	seed[4..8].copy_from_slice(&[7, 5, 3, 255]);
+	seed[24..28].copy_from_slice(&[6, 4, 2, 240]);
+
+	// 突变率为 100% 时所有等位基因都来自随机种子
+	let dna = genome::breed_dna(&dna_1, &dna_2, &seed, Perbill::one());
+	assert_eq!(&dna[..8], &[6, 7, 4, 5, 2, 3, 240, 255]);
+	assert_eq!(
+		Genome::from_dna(&dna),
+		Genome {
+			colour: Colour::Lilac,
+			pattern: Pattern::Tortoiseshell,
+			eye_shape: EyeShape::Slanted,
+			rarity: Rarity::Epic,
+		}
+	);
+
+	// 突变率为 0 时不会突变
+	let dna = genome::breed_dna(&dna_1, &dna_2, &seed, Perbill::zero());
+	assert_eq!(dna, [0u8; 16]);
+}
+
+#[test]
+fn it_works_for_traits_of() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_eq!(KittiesModule::traits_of(KITTY_ID), None);
+
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let kitty = KittiesModule::kitties(KITTY_ID).unwrap();
+		assert_eq!(KittiesModule::traits_of(KITTY_ID), Some(Genome::from_dna(&kitty.dna)));
+	});
+}
+
+#[test]
+fn breeding_has_generation_based_cooldown() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_breeding(0).generation, 0);
+
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, KITTY_NAME));
+		let child = 3;
+		assert_eq!(KittiesModule::kitty_breeding(0).next_breed_at, 1 + BREED_COOLDOWN_BASE);
+		assert_eq!(KittiesModule::kitty_breeding(1).next_breed_at, 1 + BREED_COOLDOWN_BASE);
+		assert_eq!(
+			KittiesModule::kitty_breeding(child),
+			BreedingInfo { generation: 1, next_breed_at: 1 + 2 * BREED_COOLDOWN_BASE }
+		);
+
+		// 父母在冷却期内不能繁殖
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 2, KITTY_NAME),
+			Error::<Test>::BreedingCooldown
+		);
+		System::set_block_number(1 + BREED_COOLDOWN_BASE);
+
+		// 子代的冷却期更长
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), child, 2, KITTY_NAME),
+			Error::<Test>::BreedingCooldown
+		);
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 2, KITTY_NAME));
+
+		System::set_block_number(1 + 2 * BREED_COOLDOWN_BASE);
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), child, 2, KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_breeding(5).generation, 2);
+	});
+}
+
+#[test]
+fn breed_cooldown_is_capped() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(KittiesModule::breed_cooldown(0), BREED_COOLDOWN_BASE);
+		assert_eq!(KittiesModule::breed_cooldown(1), 2 * BREED_COOLDOWN_BASE);
+		assert_eq!(KittiesModule::breed_cooldown(2), MAX_BREED_COOLDOWN);
+		assert_eq!(KittiesModule::breed_cooldown(u32::MAX), MAX_BREED_COOLDOWN);
+	});
+}
+
+#[test]
+fn related_kitties_cannot_breed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, KITTY_NAME));
+		run_to_block(1 + MAX_BREED_COOLDOWN);
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 1, 2, KITTY_NAME));
+		run_to_block(1 + 2 * MAX_BREED_COOLDOWN);
+
+		// 亲子之间不能繁殖
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 3, KITTY_NAME),
+			Error::<Test>::RelatedKitties
+		);
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 3, 1, KITTY_NAME),
+			Error::<Test>::RelatedKitties
+		);
+
+		// 有共同父母的 kitty 之间不能繁殖
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 3, 4, KITTY_NAME),
+			Error::<Test>::RelatedKitties
+		);
+
+		// 没有亲缘关系的可以繁殖
+		assert_ok!(KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 2, KITTY_NAME));
+	});
+}
+
+const SIRING_FEE: u128 = 1000;
+
+#[test]
+fn it_works_for_breed_with_sire() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_NAME));
+		let (my_kitty, sire_id) = (0, 1);
+
+		// 不能直接用别人的 kitty 繁殖
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), my_kitty, sire_id, KITTY_NAME),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::breed_with_sire(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				my_kitty,
+				sire_id,
+				SIRING_FEE,
+				KITTY_NAME
+			),
+			Error::<Test>::NotOfferedForSiring
+		);
+
+		assert_noop!(
+			KittiesModule::offer_siring(RuntimeOrigin::signed(ACCOUNT_ID), sire_id, SIRING_FEE),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::offer_siring(RuntimeOrigin::signed(ACCOUNT_ID2), sire_id, SIRING_FEE));
+		assert_eq!(KittiesModule::siring_offer(sire_id), Some(SIRING_FEE));
+		System::assert_last_event(
+			Event::SiringOffered { who: ACCOUNT_ID2, kitty_id: sire_id, fee: SIRING_FEE }.into(),
+		);
+
+		// 配种费用超过调用者接受的上限时失败
+		assert_noop!(
+			KittiesModule::breed_with_sire(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				my_kitty,
+				sire_id,
+				SIRING_FEE - 1,
+				KITTY_NAME
+			),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_noop!(
+			KittiesModule::breed_with_sire(
+				RuntimeOrigin::signed(ACCOUNT_ID2),
+				sire_id,
+				sire_id,
+				SIRING_FEE,
+				KITTY_NAME
+			),
+			Error::<Test>::SamedKittyId
+		);
+
+		assert_ok!(KittiesModule::breed_with_sire(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			my_kitty,
+			sire_id,
+			SIRING_FEE,
+			KITTY_NAME
+		));
+		let child = 2;
+		assert_eq!(KittiesModule::kitty_owner(child), Some(ACCOUNT_ID));
+		assert_eq!(KittiesModule::kitty_parents(child), Some((my_kitty, sire_id)));
+		assert_eq!(
+			Balances::free_balance(ACCOUNT_ID),
+			ACCOUNT_BALANCE - 2 * EXISTENTIAL_DEPOSIT * 10 - SIRING_FEE
+		);
+		assert_eq!(
+			Balances::free_balance(ACCOUNT_ID2),
+			ACCOUNT_BALANCE2 - EXISTENTIAL_DEPOSIT * 10 + SIRING_FEE
+		);
+		System::assert_has_event(
+			Event::SiringFeePaid { who: ACCOUNT_ID, sire_owner: ACCOUNT_ID2, sire_id, fee: SIRING_FEE }
+				.into(),
+		);
+
+		// 配种之后报价仍然有效，但配种的 kitty 需要冷却
+		assert_eq!(KittiesModule::siring_offer(sire_id), Some(SIRING_FEE));
+		assert_ok!(KittiesModule::cancel_siring(RuntimeOrigin::signed(ACCOUNT_ID2), sire_id));
+		assert!(KittiesModule::siring_offer(sire_id).is_none());
+		System::assert_last_event(
+			Event::SiringOfferCancelled { who: ACCOUNT_ID2, kitty_id: sire_id }.into(),
+		);
+		assert_noop!(
+			KittiesModule::cancel_siring(RuntimeOrigin::signed(ACCOUNT_ID2), sire_id),
+			Error::<Test>::NotOfferedForSiring
+		);
+	});
+}
+
+#[test]
+fn siring_offer_is_cancelled_when_sire_changes_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		// 转移
+		assert_ok!(KittiesModule::offer_siring(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SIRING_FEE));
+		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID));
+		assert!(KittiesModule::siring_offer(KITTY_ID).is_none());
+
+		// 出售
+		assert_ok!(KittiesModule::offer_siring(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, SIRING_FEE));
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, KITTY_PRICE));
+		assert_ok!(KittiesModule::buy(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+		assert!(KittiesModule::siring_offer(KITTY_ID).is_none());
+
+		// 销毁
+		assert_ok!(KittiesModule::offer_siring(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SIRING_FEE));
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		assert!(KittiesModule::siring_offer(KITTY_ID).is_none());
+	});
+}
+
+// 提交之后才把后来产生的 VRF 输出设为 `later_vrf`，提交时所知的一切都相同
+fn committed_kitty_dna(later_vrf: H256) -> [u8; 16] {
+	new_test_ext().execute_with(|| {
+		VrfOutput::set(&Some(H256::repeat_byte(1)));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		let secret = [7u8; 32];
+		let commitment = BlakeTwo256::hash_of(&secret);
+		assert_ok!(KittiesModule::commit_breed(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			0,
+			1,
+			commitment
+		));
+		// 提交时还没有繁殖随机数，结果无法计算
+		assert_eq!(KittiesModule::breed_seed(1), None);
+
+		VrfOutput::set(&Some(later_vrf));
+		run_to_block(3);
+		assert_ok!(KittiesModule::reveal_breed(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			secret,
+			KITTY_NAME
+		));
+		KittiesModule::kitties(2).unwrap().dna
+	})
+}
+
+#[test]
+fn breeding_is_not_predictable_at_commit_time() {
+	// 提交时的父区块哈希、VRF 输出和秘密值都相同，结果只随提交之后产生的随机数变化
+	let dna = committed_kitty_dna(H256::repeat_byte(2));
+	assert_eq!(committed_kitty_dna(H256::repeat_byte(2)), dna);
+	assert_ne!(committed_kitty_dna(H256::repeat_byte(3)), dna);
+}
+
+#[test]
+fn it_works_for_commit_reveal_breed() {
+	new_test_ext().execute_with(|| {
+		VrfOutput::set(&Some(H256::repeat_byte(1)));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		let secret = [7u8; 32];
+		let commitment = BlakeTwo256::hash_of(&secret);
+		assert_noop!(
+			KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME),
+			Error::<Test>::NoCommitment
+		);
+
+		assert_ok!(KittiesModule::commit_breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, commitment));
+		System::assert_last_event(
+			Event::BreedCommitted { who: ACCOUNT_ID, kitty_id_1: 0, kitty_id_2: 1 }.into(),
+		);
+		// 提交时父母就进入冷却期
+		assert_eq!(KittiesModule::kitty_breeding(0).next_breed_at, 1 + BREED_COOLDOWN_BASE);
+		assert_eq!(KittiesModule::kitty_breeding(1).next_breed_at, 1 + BREED_COOLDOWN_BASE);
+		assert_noop!(
+			KittiesModule::commit_breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, commitment),
+			Error::<Test>::CommitmentExists
+		);
+
+		// 提交所在区块的 VRF 输出在提交时已经可以知道
+		assert_noop!(
+			KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME),
+			Error::<Test>::RandomnessNotReady
+		);
+		run_to_block(2);
+		assert_noop!(
+			KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME),
+			Error::<Test>::RandomnessNotReady
+		);
+
+		run_to_block(3);
+		assert!(KittiesModule::breed_seed(1).is_some());
+		assert_noop!(
+			KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), [8u8; 32], KITTY_NAME),
+			Error::<Test>::InvalidReveal
+		);
+		assert_ok!(KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME));
+
+		let child = 2;
+		assert_eq!(KittiesModule::kitty_owner(child), Some(ACCOUNT_ID));
+		assert_eq!(KittiesModule::kitty_parents(child), Some((0, 1)));
+		assert_eq!(KittiesModule::kitty_breeding(child).generation, 1);
+		assert!(KittiesModule::breed_commitment(ACCOUNT_ID).is_none());
+		// 揭示时不会重新计算父母的冷却期
+		assert_eq!(KittiesModule::kitty_breeding(0).next_breed_at, 1 + BREED_COOLDOWN_BASE);
+	});
+}
+
+// 在区块 1 提交，在区块 `reveal_at` 揭示，揭示前 VRF 输出一直在变化
+fn revealed_kitty_dna(reveal_at: u64) -> [u8; 16] {
+	new_test_ext().execute_with(|| {
+		VrfOutput::set(&Some(H256::repeat_byte(1)));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		let secret = [7u8; 32];
+		let commitment = BlakeTwo256::hash_of(&secret);
+		assert_ok!(KittiesModule::commit_breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, commitment));
+		while System::block_number() < reveal_at {
+			run_to_block(System::block_number() + 1);
+			VrfOutput::set(&Some(H256::repeat_byte(System::block_number() as u8)));
+		}
+
+		assert_ok!(KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME));
+		KittiesModule::kitties(2).unwrap().dna
+	})
+}
+
+#[test]
+fn revealing_later_does_not_change_the_child() {
+	let dna = revealed_kitty_dna(3);
+	for reveal_at in 4..=1 + BREED_REVEAL_WINDOW as u64 {
+		assert_eq!(revealed_kitty_dna(reveal_at), dna);
+	}
+}
+
+#[test]
+fn breed_commitment_is_forfeited_after_reveal_window() {
+	new_test_ext().execute_with(|| {
+		VrfOutput::set(&Some(H256::repeat_byte(1)));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		let secret = [7u8; 32];
+		let commitment = BlakeTwo256::hash_of(&secret);
+		assert_ok!(KittiesModule::commit_breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, commitment));
+
+		// 超过揭示窗口后承诺被作废，不会产生子代，随机数也被清理
+		run_to_block(2 + BREED_REVEAL_WINDOW as u64);
+		assert!(KittiesModule::breed_seed(1).is_none());
+		assert_ok!(KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME));
+		System::assert_last_event(Event::BreedCommitmentForfeited { who: ACCOUNT_ID }.into());
+		assert!(KittiesModule::breed_commitment(ACCOUNT_ID).is_none());
+		assert!(KittiesModule::kitties(2).is_none());
+	});
+}
+
+#[test]
+fn commit_breed_drops_unseeded_blocks_outside_reveal_window() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		// 之前的每个区块都还没有随机数，队列已满
+		let now = 1 + BREED_REVEAL_WINDOW as u64;
+		run_to_block(now);
+		let unseeded = BoundedVec::try_from((1..now).collect::<Vec<_>>()).unwrap();
+		UnseededBreedBlocks::<Test>::put(unseeded);
+
+		// 揭示窗口之外的第一个区块被去掉，本区块总能加入
+		let commitment = BlakeTwo256::hash_of(&[7u8; 32]);
+		assert_ok!(KittiesModule::commit_breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, commitment));
+		assert_eq!(UnseededBreedBlocks::<Test>::get().into_inner(), (2..=now).collect::<Vec<_>>());
+	});
+}
+
+#[test]
+fn burn_is_rejected_while_kitty_is_committed() {
+	new_test_ext().execute_with(|| {
+		VrfOutput::set(&Some(H256::repeat_byte(1)));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		let secret = [7u8; 32];
+		let commitment = BlakeTwo256::hash_of(&secret);
+		assert_ok!(KittiesModule::commit_breed(
+			RuntimeOrigin::signed(ACCOUNT_ID),
+			0,
+			1,
+			commitment
+		));
+		assert_eq!(KittiesModule::kitty_committed_by(0), Some(ACCOUNT_ID));
+
+		// 揭示窗口内 kitty 还被承诺引用，销毁后 id 被重用时揭示会用到新的 kitty
+		assert_noop!(
+			KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), 0),
+			Error::<Test>::KittyCommitted
+		);
+
+		// 作废的承诺在销毁时被清除，之后不能再揭示
+		run_to_block(2 + BREED_REVEAL_WINDOW as u64);
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), 0));
+		System::assert_has_event(Event::BreedCommitmentForfeited { who: ACCOUNT_ID }.into());
+		assert!(KittiesModule::breed_commitment(ACCOUNT_ID).is_none());
+		assert!(KittiesModule::kitty_committed_by(1).is_none());
+		assert_noop!(
+			KittiesModule::reveal_breed(RuntimeOrigin::signed(ACCOUNT_ID), secret, KITTY_NAME),
+			Error::<Test>::NoCommitment
+		);
+	});
+}
+
+#[test]
+fn it_works_for_cancel_breed_commitment() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		assert_noop!(
+			KittiesModule::cancel_breed_commitment(RuntimeOrigin::signed(ACCOUNT_ID)),
+			Error::<Test>::NoCommitment
+		);
+
+		let commitment = BlakeTwo256::hash_of(&[7u8; 32]);
+		assert_ok!(KittiesModule::commit_breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, commitment));
+		assert_ok!(KittiesModule::cancel_breed_commitment(RuntimeOrigin::signed(ACCOUNT_ID)));
+		assert!(KittiesModule::breed_commitment(ACCOUNT_ID).is_none());
+		assert!(KittiesModule::kitty_committed_by(0).is_none());
+		System::assert_last_event(Event::BreedCommitmentCancelled { who: ACCOUNT_ID }.into());
+
+		// 放弃之后父母仍然在冷却期
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), 0, 1, KITTY_NAME),
+			Error::<Test>::BreedingCooldown
+		);
+	});
+}
+
+// 只依赖 nonfungible trait 的通用调用方
+fn give_away<Nft: nonfungible::Transfer<u64>>(item: &Nft::ItemId, from: &u64, to: &u64) -> DispatchResult {
+	ensure_owner::<Nft>(item, from)?;
+	Nft::transfer(item, to)
+}
+
+fn ensure_owner<Nft: nonfungible::Inspect<u64>>(item: &Nft::ItemId, who: &u64) -> DispatchResult {
+	frame_support::ensure!(Nft::owner(item).as_ref() == Some(who), "not the owner");
+	frame_support::ensure!(Nft::can_transfer(item), "item is locked");
+	Ok(())
+}
+
+#[test]
+fn it_works_for_nonfungible_traits() {
+	use nonfungible::{Inspect, Mutate};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		let kitty = KittiesModule::kitties(KITTY_ID).unwrap();
+		assert_eq!(<KittiesModule as Inspect<u64>>::owner(&KITTY_ID), Some(ACCOUNT_ID));
+		assert_eq!(KittiesModule::attribute(&KITTY_ID, b"dna"), Some(kitty.dna.to_vec()));
+		assert_eq!(KittiesModule::attribute(&KITTY_ID, b"name"), Some(KITTY_NAME.to_vec()));
+		assert_eq!(KittiesModule::attribute(&KITTY_ID, b"unknown"), None);
+
+		// 通过 trait 转移，和 transfer 调用一样更新索引和押金
+		assert_ok!(give_away::<KittiesModule>(&KITTY_ID, &ACCOUNT_ID, &ACCOUNT_ID2));
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID2));
+		assert!(KittiesModule::owned_kitties(ACCOUNT_ID).is_empty());
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID2).to_vec(), vec![KITTY_ID]);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), EXISTENTIAL_DEPOSIT * 10);
+		System::assert_last_event(
+			Event::KittyTransferred { who: ACCOUNT_ID, recipient: ACCOUNT_ID2, kitty_id: KITTY_ID }
+				.into(),
+		);
+		assert_noop!(give_away::<KittiesModule>(&KITTY_ID, &ACCOUNT_ID, &ACCOUNT_ID2), "not the owner");
+
+		// 拍卖中的 kitty 不能转移
+		assert_ok!(KittiesModule::create_auction(
+			RuntimeOrigin::signed(ACCOUNT_ID2),
+			KITTY_ID,
+			AuctionKind::English { reserve: KITTY_PRICE },
+			10
+		));
+		assert!(!KittiesModule::can_transfer(&KITTY_ID));
+		assert_noop!(
+			<KittiesModule as nonfungible::Transfer<u64>>::transfer(&KITTY_ID, &ACCOUNT_ID),
+			Error::<Test>::KittyInAuction
+		);
+		run_to_block(11);
+
+		assert_noop!(
+			<KittiesModule as Mutate<u64>>::burn(&KITTY_ID, Some(&ACCOUNT_ID)),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(<KittiesModule as Mutate<u64>>::burn(&KITTY_ID, Some(&ACCOUNT_ID2)));
+		assert_eq!(<KittiesModule as Inspect<u64>>::owner(&KITTY_ID), None);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), 0);
+	});
+}
+
+#[test]
+fn it_works_for_approve_and_transfer_from() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		// 只有所有者或操作员可以授权，不能授权给所有者自己
+		assert_noop!(
+			KittiesModule::approve(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, Some(ACCOUNT_ID2)),
+			Error::<Test>::NotApproved
+		);
+		assert_noop!(
+			KittiesModule::approve(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, Some(ACCOUNT_ID)),
+			Error::<Test>::ApproveToOwner
+		);
+		assert_noop!(
+			KittiesModule::transfer_from(
+				RuntimeOrigin::signed(ACCOUNT_ID2),
+				ACCOUNT_ID,
+				ACCOUNT_ID3,
+				KITTY_ID
+			),
+			Error::<Test>::NotApproved
+		);
+
+		assert_ok!(KittiesModule::approve(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, Some(ACCOUNT_ID2)));
+		assert_eq!(KittiesModule::approved(KITTY_ID), Some(ACCOUNT_ID2));
+		System::assert_last_event(
+			Event::Approval { owner: ACCOUNT_ID, approved: Some(ACCOUNT_ID2), kitty_id: KITTY_ID }
+				.into(),
+		);
+
+		// from 必须是当前所有者
+		assert_noop!(
+			KittiesModule::transfer_from(
+				RuntimeOrigin::signed(ACCOUNT_ID2),
+				ACCOUNT_ID3,
+				ACCOUNT_ID2,
+				KITTY_ID
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::transfer_from(
+			RuntimeOrigin::signed(ACCOUNT_ID2),
+			ACCOUNT_ID,
+			ACCOUNT_ID3,
+			KITTY_ID
+		));
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID3));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), EXISTENTIAL_DEPOSIT * 10);
+		System::assert_last_event(
+			Event::KittyTransferred { who: ACCOUNT_ID, recipient: ACCOUNT_ID3, kitty_id: KITTY_ID }
+				.into(),
+		);
+
+		// 转移后授权失效
+		assert!(KittiesModule::approved(KITTY_ID).is_none());
+		assert_noop!(
+			KittiesModule::transfer_from(
+				RuntimeOrigin::signed(ACCOUNT_ID2),
+				ACCOUNT_ID3,
+				ACCOUNT_ID2,
+				KITTY_ID
+			),
+			Error::<Test>::NotApproved
+		);
+
+		// 取消授权
+		assert_ok!(KittiesModule::approve(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, Some(ACCOUNT_ID2)));
+		assert_ok!(KittiesModule::approve(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, None));
+		assert!(KittiesModule::approved(KITTY_ID).is_none());
+		System::assert_last_event(
+			Event::Approval { owner: ACCOUNT_ID3, approved: None, kitty_id: KITTY_ID }.into(),
+		);
+	});
+}
+
+#[test]
+fn it_works_for_set_approval_for_all() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		assert_noop!(
+			KittiesModule::set_approval_for_all(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID, true),
+			Error::<Test>::ApproveToOwner
+		);
+		assert_ok!(KittiesModule::set_approval_for_all(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, true));
+		assert!(KittiesModule::is_approved_for_all(&ACCOUNT_ID, &ACCOUNT_ID2));
+		System::assert_last_event(
+			Event::ApprovalForAll { owner: ACCOUNT_ID, operator: ACCOUNT_ID2, approved: true }.into(),
+		);
+
+		// 操作员可以转移所有者的任意 kitty，也可以授权给别人
+		assert_ok!(KittiesModule::transfer_from(
+			RuntimeOrigin::signed(ACCOUNT_ID2),
+			ACCOUNT_ID,
+			ACCOUNT_ID3,
+			KITTY_ID
+		));
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID3));
+		assert_ok!(KittiesModule::approve(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1, Some(ACCOUNT_ID3)));
+		assert_eq!(KittiesModule::approved(KITTY_ID + 1), Some(ACCOUNT_ID3));
+		System::assert_last_event(
+			Event::Approval { owner: ACCOUNT_ID, approved: Some(ACCOUNT_ID3), kitty_id: KITTY_ID + 1 }
+				.into(),
+		);
+
+		// 操作员的授权不会随 kitty 转给新的所有者
+		assert_noop!(
+			KittiesModule::transfer_from(
+				RuntimeOrigin::signed(ACCOUNT_ID2),
+				ACCOUNT_ID3,
+				ACCOUNT_ID,
+				KITTY_ID
+			),
+			Error::<Test>::NotApproved
+		);
+
+		assert_ok!(KittiesModule::set_approval_for_all(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, false));
+		assert!(!KittiesModule::is_approved_for_all(&ACCOUNT_ID, &ACCOUNT_ID2));
+		assert_noop!(
+			KittiesModule::transfer_from(
+				RuntimeOrigin::signed(ACCOUNT_ID2),
+				ACCOUNT_ID,
+				ACCOUNT_ID3,
+				KITTY_ID + 1
+			),
+			Error::<Test>::NotApproved
+		);
+	});
+}
+
+#[test]
+fn kitty_names_are_validated() {
+	assert_eq!(crate::decode_name(b"test0000"), Some("test0000"));
+	assert_eq!(crate::decode_name(b"kitty\0\0\0"), Some("kitty"));
+	assert_eq!(crate::decode_name(&[0xe5, 0x92, 0xaa, 0xe5, 0x92, 0xaa, 0, 0]), Some("咪咪"));
+	// 空名字、非 UTF-8、控制字符和中间的 0 都不允许
+	assert_eq!(crate::decode_name(&[0; 8]), None);
+	assert_eq!(crate::decode_name(&[0xff, b'a', b'b', b'c', 0, 0, 0, 0]), None);
+	assert_eq!(crate::decode_name(b"kit\nty\0\0"), None);
+	assert_eq!(crate::decode_name(b"kit\0ty\0\0"), None);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_noop!(
+			KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), [0; 8]),
+			Error::<Test>::InvalidName
+		);
+	});
+}
+
+#[test]
+fn it_works_for_rename() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_eq!(KittiesModule::name_of(KITTY_ID), Some("test0000".into()));
+
+		let name = *b"tom\0\0\0\0\0";
+		assert_noop!(
+			KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, name),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID + 1, name),
+			Error::<Test>::InvalidKittyId
+		);
+		assert_noop!(
+			KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, *b"\x07bell\0\0\0"),
+			Error::<Test>::InvalidName
+		);
+
+		assert_ok!(KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, name));
+		assert_eq!(KittiesModule::kitties(KITTY_ID).unwrap().name, name);
+		assert_eq!(KittiesModule::name_of(KITTY_ID), Some("tom".into()));
+		System::assert_last_event(
+			Event::KittyRenamed { who: ACCOUNT_ID, kitty_id: KITTY_ID, name }.into(),
+		);
+
+		// 名字不要求唯一时不使用索引
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), name));
+		assert!(KittiesModule::kitty_by_name(name).is_none());
+	});
+}
+
+#[test]
+fn unique_names_are_indexed() {
+	new_test_ext().execute_with(|| {
+		UniqueNames::set(&true);
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		let other_name = *b"other000";
+
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_by_name(KITTY_NAME), Some(KITTY_ID));
+		assert_noop!(
+			KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_NAME),
+			Error::<Test>::NameTaken
+		);
+
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID2), other_name));
+		assert_noop!(
+			KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1, KITTY_NAME),
+			Error::<Test>::NameTaken
+		);
+		// 重命名为原来的名字不会冲突
+		assert_ok!(KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1, other_name));
+
+		// 重命名后旧名字可以被使用
+		assert_ok!(KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, *b"renamed0"));
+		assert_eq!(KittiesModule::kitty_by_name(*b"renamed0"), Some(KITTY_ID));
+		assert!(KittiesModule::kitty_by_name(KITTY_NAME).is_none());
+		assert_ok!(KittiesModule::rename(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1, KITTY_NAME));
+		assert_eq!(KittiesModule::kitty_by_name(KITTY_NAME), Some(KITTY_ID + 1));
+		assert!(KittiesModule::kitty_by_name(other_name).is_none());
+
+		// 销毁后名字被释放
+		assert_ok!(KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1));
+		assert!(KittiesModule::kitty_by_name(KITTY_NAME).is_none());
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+	});
+}
+
+#[test]
+fn migrate_to_v5_indexes_names() {
+	new_test_ext().execute_with(|| {
+		UniqueNames::set(&true);
+		StorageVersion::new(4).put::<KittiesModule>();
+		let kitty = crate::Kitty { dna: [0; 16], name: KITTY_NAME };
+		crate::Kitties::<Test>::insert(0, kitty);
+		crate::Kitties::<Test>::insert(1, crate::Kitty { name: *b"other000", ..kitty });
+		crate::Kitties::<Test>::insert(2, kitty);
+
+		VersionedMigration::<Test, v5::MigrateToV5<Test>>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 5);
+		assert_eq!(KittiesModule::kitty_by_name(*b"other000"), Some(1));
+		// 重名的 kitty 只有一个被索引
+		assert!(matches!(KittiesModule::kitty_by_name(KITTY_NAME), Some(0) | Some(2)));
+	});
+}
+
+#[test]
+fn migrate_to_v6_counts_children() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(5).put::<KittiesModule>();
+		crate::KittyParents::<Test>::insert(2, (0, 1));
+		crate::KittyParents::<Test>::insert(3, (0, 1));
+		crate::FreeKittyIds::<Test>::put(BoundedVec::truncate_from(vec![0, 4]));
+
+		VersionedMigration::<Test, v6::MigrateToV6<Test>>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 6);
+		assert_eq!(KittiesModule::kitty_children(0), 2);
+		assert_eq!(KittiesModule::kitty_children(1), 2);
+		// 还有子代的 id 从空闲列表中移除
+		assert_eq!(KittiesModule::free_kitty_ids().to_vec(), vec![4]);
+	});
+}
+
+#[test]
+fn migrate_to_v7_names_auction_bids() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		StorageVersion::new(6).put::<KittiesModule>();
+		// 版本 6 的出价是普通预留
+		assert_ok!(Balances::reserve(&ACCOUNT_ID2, KITTY_PRICE));
+		let auction = crate::Auction {
+			seller: ACCOUNT_ID,
+			kind: AuctionKind::English { reserve: KITTY_PRICE },
+			start: 1,
+			end: 5,
+			highest_bid: Some((ACCOUNT_ID2, KITTY_PRICE)),
+		};
+		crate::Auctions::<Test>::insert(KITTY_ID, auction);
+
+		VersionedMigration::<Test, v7::MigrateToV7<Test>>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 7);
+		assert_eq!(Balances::reserved_balance_named(&AUCTION_RESERVE_ID, &ACCOUNT_ID2), KITTY_PRICE);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), KITTY_PRICE);
+	});
+}
+
+#[test]
+fn migrate_to_v8_indexes_breed_commitments() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(7).put::<KittiesModule>();
+		let commitment = crate::BreedCommitment {
+			kitty_id_1: 0,
+			kitty_id_2: 1,
+			commitment: BlakeTwo256::hash_of(&[7u8; 32]),
+			committed_at: 1,
+		};
+		crate::BreedCommitments::<Test>::insert(ACCOUNT_ID, commitment);
+
+		VersionedMigration::<Test, v8::MigrateToV8<Test>>::on_runtime_upgrade();
+
+		assert_eq!(KittiesModule::on_chain_storage_version(), 8);
+		assert_eq!(KittiesModule::kitty_committed_by(0), Some(ACCOUNT_ID));
+		assert_eq!(KittiesModule::kitty_committed_by(1), Some(ACCOUNT_ID));
+	});
+}
+
+#[test]
+fn migrations_run_from_v0_across_blocks() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<KittiesModule>();
+		// 版本 0 的 kitty 只有 DNA
+		for kitty_id in 0..5u32 {
+			v1::v0::Kitties::<Test>::insert(kitty_id, v1::OldKitty([kitty_id as u8; 16]));
+		}
+
+		// 一个区块最多迁移 MIGRATION_BATCH_SIZE 个 kitty
+		Migrations::<Test>::on_runtime_upgrade();
+		assert_eq!(KittiesModule::on_chain_storage_version(), 0);
+		assert!(KittiesModule::migration_in_progress());
+
+		// 下一个区块完成 v1 并开始 v2
+		run_to_block(2);
+		assert_eq!(KittiesModule::on_chain_storage_version(), 1);
+		assert!(KittiesModule::migration_in_progress());
+
+		run_to_block(3);
+		assert_eq!(KittiesModule::on_chain_storage_version(), 8);
+		assert!(!KittiesModule::migration_in_progress());
+		for kitty_id in 0..5u32 {
+			assert_eq!(
+				KittiesModule::kitties(kitty_id),
+				Some(crate::Kitty { dna: [kitty_id as u8; 16], name: *b"None\0\0\0\0" })
+			);
+		}
+
+		// 迁移完成后再次运行不会做任何事
+		Migrations::<Test>::on_runtime_upgrade();
+		assert_eq!(KittiesModule::on_chain_storage_version(), 8);
+		assert!(!KittiesModule::migration_in_progress());
+	});
+}
+
+
+const LOAN_FEE: u128 = 1000;
+
+#[test]
+fn it_works_for_lend_and_borrow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		assert_noop!(
+			KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, ACCOUNT_ID2, 11, LOAN_FEE),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID, 11, LOAN_FEE),
+			Error::<Test>::LendToOwner
+		);
+		assert_noop!(
+			KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 1, LOAN_FEE),
+			Error::<Test>::InvalidLoanDuration
+		);
+		assert_noop!(
+			KittiesModule::lend(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				KITTY_ID,
+				ACCOUNT_ID2,
+				2 + MAX_LOAN_DURATION,
+				LOAN_FEE
+			),
+			Error::<Test>::InvalidLoanDuration
+		);
+		assert_ok!(KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 11, LOAN_FEE));
+		System::assert_last_event(
+			Event::LoanOffered {
+				who: ACCOUNT_ID,
+				borrower: ACCOUNT_ID2,
+				kitty_id: KITTY_ID,
+				until: 11,
+				fee: LOAN_FEE,
+			}
+			.into(),
+		);
+
+		// 只有指定的借用者可以接受，并且费用不能超过上限
+		assert_noop!(
+			KittiesModule::borrow(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, LOAN_FEE),
+			Error::<Test>::NotBorrower
+		);
+		assert_noop!(
+			KittiesModule::borrow(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, LOAN_FEE - 1),
+			Error::<Test>::PriceTooHigh
+		);
+		let owner_balance = Balances::free_balance(ACCOUNT_ID);
+		let borrower_balance = Balances::free_balance(ACCOUNT_ID2);
+		assert_ok!(KittiesModule::borrow(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, LOAN_FEE));
+		System::assert_last_event(
+			Event::KittyLent {
+				owner: ACCOUNT_ID,
+				borrower: ACCOUNT_ID2,
+				kitty_id: KITTY_ID,
+				until: 11,
+				fee: LOAN_FEE,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), owner_balance + LOAN_FEE);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), borrower_balance - LOAN_FEE);
+		assert!(KittiesModule::loan_offer(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
+		assert_eq!(KittiesModule::kitty_user(KITTY_ID), Some(ACCOUNT_ID2));
+
+		// 出借期间不能转移、出售、拍卖、销毁或者再次出借
+		assert_noop!(
+			KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID3, KITTY_ID),
+			Error::<Test>::KittyLent
+		);
+		assert_noop!(
+			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::KittyLent
+		);
+		assert_noop!(
+			KittiesModule::create_auction(
+				RuntimeOrigin::signed(ACCOUNT_ID),
+				KITTY_ID,
+				AuctionKind::English { reserve: KITTY_PRICE },
+				10
+			),
+			Error::<Test>::KittyLent
+		);
+		assert_noop!(
+			KittiesModule::burn(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::KittyLent
+		);
+		assert_noop!(
+			KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID3, 11, LOAN_FEE),
+			Error::<Test>::KittyLent
+		);
+		assert!(!<KittiesModule as nonfungible::Inspect<u64>>::can_transfer(&KITTY_ID));
+
+		// 出借期间只有借用者可以用它繁殖
+		assert_noop!(
+			KittiesModule::breed(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_ID + 2, KITTY_NAME),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::offer_siring(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, LOAN_FEE),
+			Error::<Test>::KittyLent
+		);
+		assert_ok!(KittiesModule::breed(
+			RuntimeOrigin::signed(ACCOUNT_ID2),
+			KITTY_ID,
+			KITTY_ID + 1,
+			KITTY_NAME
+		));
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID + 3), Some(ACCOUNT_ID2));
+
+		// 到期后自动归还
+		run_to_block(11);
+		System::assert_has_event(
+			Event::LoanEnded { owner: ACCOUNT_ID, borrower: ACCOUNT_ID2, kitty_id: KITTY_ID }.into(),
+		);
+		assert!(KittiesModule::kitty_loan(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_user(KITTY_ID), Some(ACCOUNT_ID));
+		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID));
+	});
+}
+
+#[test]
+fn loan_offers_are_cancelled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+
+		assert_noop!(
+			KittiesModule::cancel_lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::NoLoanOffer
+		);
+		assert_ok!(KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 11, LOAN_FEE));
+		assert_noop!(
+			KittiesModule::cancel_lend(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(KittiesModule::cancel_lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		System::assert_last_event(
+			Event::LoanOfferCancelled { who: ACCOUNT_ID, kitty_id: KITTY_ID }.into(),
+		);
+		assert_noop!(
+			KittiesModule::borrow(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, LOAN_FEE),
+			Error::<Test>::NoLoanOffer
+		);
+
+		// 报价之后上架的 kitty 不能被借用
+		assert_ok!(KittiesModule::lend(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 11, LOAN_FEE));
+		assert_ok!(KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE));
+		assert_noop!(
+			KittiesModule::borrow(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, LOAN_FEE),
+			Error::<Test>::AlreadyOnSale
+		);
+		assert_ok!(KittiesModule::cancel_sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+
+		// 过期的报价不能被接受
+		run_to_block(11);
+		assert_noop!(
+			KittiesModule::borrow(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, LOAN_FEE),
+			Error::<Test>::InvalidLoanDuration
+		);
+
+		// 转移后报价失效
+		assert_ok!(KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID));
+		assert!(KittiesModule::loan_offer(KITTY_ID).is_none());
+	});
+}
+
+const SHARES: u128 = 100;
+const BUYOUT_PRICE: u128 = 1000;
+
+#[test]
+fn it_works_for_fractionalize_and_redeem() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let deposit = KittiesModule::kitty_deposit(KITTY_ID);
+
+		assert_noop!(
+			KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, SHARES),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, 0),
+			Error::<Test>::InvalidShares
+		);
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SHARES));
+		System::assert_last_event(
+			Event::KittyFractionalized { who: ACCOUNT_ID, kitty_id: KITTY_ID, shares: SHARES }.into(),
+		);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(*PALLET_ACCOUNT_ID));
+		assert!(KittiesModule::owned_kitties(ACCOUNT_ID).is_empty());
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID), SHARES);
+		// 押金仍然预留在拆分 kitty 的账户中
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), deposit);
+
+		// 拆分后 kitty 不能再被转移、出售或销毁
+		assert_noop!(
+			KittiesModule::transfer(RuntimeOrigin::signed(ACCOUNT_ID), ACCOUNT_ID2, KITTY_ID),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::sale(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, KITTY_PRICE),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			<KittiesModule as nonfungible::Transfer<u64>>::transfer(&KITTY_ID, &ACCOUNT_ID2),
+			Error::<Test>::KittyFractionalized
+		);
+		assert_noop!(
+			<KittiesModule as nonfungible::Mutate<u64>>::burn(&KITTY_ID, None),
+			Error::<Test>::KittyFractionalized
+		);
+		assert!(!<KittiesModule as nonfungible::Inspect<u64>>::can_transfer(&KITTY_ID));
+
+		// 只有持有全部份额的账户可以赎回
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 40));
+		assert_noop!(
+			KittiesModule::redeem(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::NotAllShares
+		);
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 60));
+		assert_ok!(KittiesModule::redeem(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		System::assert_last_event(Event::KittyRedeemed { who: ACCOUNT_ID2, kitty_id: KITTY_ID }.into());
+		assert!(KittiesModule::fractions(KITTY_ID).is_none());
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID2));
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID2).into_inner(), vec![KITTY_ID]);
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), 0);
+		// 押金随 kitty 转给赎回的账户
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), deposit);
+		assert_noop!(
+			KittiesModule::redeem(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			Error::<Test>::NotFractionalized
+		);
+
+		// 赎回时份额资产已经删除，可以再次拆分
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, SHARES));
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), SHARES);
+	});
+}
+
+#[test]
+fn it_works_for_buyout() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		let deposit = KittiesModule::kitty_deposit(KITTY_ID);
+		for kitty_id in [KITTY_ID, KITTY_ID + 1] {
+			assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), kitty_id, SHARES));
+			assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), kitty_id, ACCOUNT_ID2, 40));
+		}
+
+		assert_noop!(
+			KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID),
+			Error::<Test>::NoBuyoutOffer
+		);
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+		System::assert_last_event(
+			Event::BuyoutOffered { who: ACCOUNT_ID3, kitty_id: KITTY_ID, price: BUYOUT_PRICE }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), BUYOUT_PRICE);
+		assert_noop!(
+			KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, BUYOUT_PRICE),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID),
+			Error::<Test>::NoShares
+		);
+
+		// 40% 的份额同意时还不能买断，投票的份额锁定在 pallet 账户中
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		System::assert_last_event(
+			Event::BuyoutVoted { who: ACCOUNT_ID2, kitty_id: KITTY_ID, shares: 40 }.into(),
+		);
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), 0);
+		assert_eq!(KittyShares::balance(KITTY_ID, *PALLET_ACCOUNT_ID), 40);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(*PALLET_ACCOUNT_ID));
+		assert_noop!(
+			KittiesModule::redeem(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::NotAllShares
+		);
+
+		// 达到一半后买断完成，投票者按份额分得出价
+		let balance_1 = Balances::free_balance(ACCOUNT_ID);
+		let balance_2 = Balances::free_balance(ACCOUNT_ID2);
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		System::assert_last_event(
+			Event::BuyoutAccepted { buyer: ACCOUNT_ID3, kitty_id: KITTY_ID, price: BUYOUT_PRICE }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), balance_1 + 600);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), balance_2 + 400);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID3));
+		assert_eq!(KittiesModule::owned_kitties(ACCOUNT_ID3).into_inner(), vec![KITTY_ID]);
+		assert!(KittiesModule::fractions(KITTY_ID).is_none());
+		assert!(KittiesModule::buyout_offer(KITTY_ID).is_none());
+		assert!(KittiesModule::buyout_claim(KITTY_ID).is_none());
+		// 锁定的份额在买断完成时销毁
+		assert_eq!(KittyShares::balance(KITTY_ID, *PALLET_ACCOUNT_ID), 0);
+		// 买家的出价付给投票者，押金从拆分 kitty 的账户转给买家
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), deposit);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID), deposit);
+
+		// 持有 60% 的账户同意后立即买断，剩下的持有者自己领取
+		assert_ok!(KittiesModule::offer_buyout(
+			RuntimeOrigin::signed(ACCOUNT_ID3),
+			KITTY_ID + 1,
+			BUYOUT_PRICE
+		));
+		assert_noop!(
+			KittiesModule::claim_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1),
+			Error::<Test>::NoBuyoutClaim
+		);
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID + 1));
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID + 1), Some(ACCOUNT_ID3));
+		assert_eq!(Balances::free_balance(ACCOUNT_ID), balance_1 + 1200);
+		assert_eq!(
+			KittiesModule::buyout_claim(KITTY_ID + 1),
+			Some(BuyoutClaim { buyer: ACCOUNT_ID3, price: 400, shares: 40 })
+		);
+
+		assert_noop!(
+			KittiesModule::claim_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID + 1),
+			Error::<Test>::NoShares
+		);
+		assert_ok!(KittiesModule::claim_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID + 1));
+		System::assert_last_event(
+			Event::BuyoutClaimed {
+				who: ACCOUNT_ID2,
+				kitty_id: KITTY_ID + 1,
+				shares: 40,
+				amount: 400,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), balance_2 + 800);
+		assert!(KittiesModule::buyout_claim(KITTY_ID + 1).is_none());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), deposit * 2);
+	});
+}
+
+#[test]
+fn buyout_offers_are_replaced_and_cancelled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SHARES));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 10));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID3, 10));
+
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID));
+
+		// 投票账户数达到上限
+		assert_noop!(
+			KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::TooManyVoters
+		);
+
+		// 更高的出价替换之前的报价，投票作废，锁定的份额退还给投票者
+		assert_ok!(KittiesModule::offer_buyout(
+			RuntimeOrigin::signed(ACCOUNT_ID2),
+			KITTY_ID,
+			BUYOUT_PRICE * 2
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), BUYOUT_PRICE * 2);
+		assert!(KittiesModule::buyout_votes(KITTY_ID).is_empty());
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), 10);
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID3), 10);
+
+		// 取消后锁定的份额退还给投票者
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID));
+		assert_eq!(KittyShares::balance(KITTY_ID, *PALLET_ACCOUNT_ID), 10);
+		assert_noop!(
+			KittiesModule::cancel_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID),
+			Error::<Test>::NotBuyer
+		);
+		assert_ok!(KittiesModule::cancel_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		System::assert_last_event(
+			Event::BuyoutCancelled { who: ACCOUNT_ID2, kitty_id: KITTY_ID }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID2), 0);
+		assert!(KittiesModule::buyout_offer(KITTY_ID).is_none());
+		assert!(KittiesModule::buyout_votes(KITTY_ID).is_empty());
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID3), 10);
+		assert_eq!(KittyShares::balance(KITTY_ID, *PALLET_ACCOUNT_ID), 0);
+
+		// 赎回时取消没有投票的报价
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID, ACCOUNT_ID, 10));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, ACCOUNT_ID, 10));
+		assert_ok!(KittiesModule::redeem(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		System::assert_has_event(
+			Event::BuyoutCancelled { who: ACCOUNT_ID3, kitty_id: KITTY_ID }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), 0);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID));
+	});
+}
+
+#[test]
+fn buyout_votes_are_withdrawn_and_offers_expire() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SHARES));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 40));
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+		assert_eq!(KittiesModule::buyout_offer(KITTY_ID).unwrap().expires_at, 1 + BUYOUT_OFFER_DURATION);
+
+		// 投票者可以撤回投票，取回锁定的份额
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_noop!(
+			KittiesModule::withdraw_buyout_vote(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID),
+			Error::<Test>::NotVoted
+		);
+		assert_ok!(KittiesModule::withdraw_buyout_vote(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		System::assert_last_event(
+			Event::BuyoutVoteWithdrawn { who: ACCOUNT_ID2, kitty_id: KITTY_ID, shares: 40 }.into(),
+		);
+		assert!(KittiesModule::buyout_votes(KITTY_ID).is_empty());
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), 40);
+		assert_eq!(KittyShares::balance(KITTY_ID, *PALLET_ACCOUNT_ID), 0);
+
+		// 过期的报价不能再投票，任何人都可以取消
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_noop!(
+			KittiesModule::cancel_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::NotBuyer
+		);
+		run_to_block(1 + BUYOUT_OFFER_DURATION);
+		assert_noop!(
+			KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID),
+			Error::<Test>::BuyoutExpired
+		);
+		assert_ok!(KittiesModule::cancel_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		System::assert_last_event(
+			Event::BuyoutCancelled { who: ACCOUNT_ID3, kitty_id: KITTY_ID }.into(),
+		);
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), 40);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), 0);
+
+		// 过期的报价可以被更低的出价替换
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+		run_to_block(1 + BUYOUT_OFFER_DURATION * 2);
+		assert_ok!(KittiesModule::offer_buyout(
+			RuntimeOrigin::signed(ACCOUNT_ID2),
+			KITTY_ID,
+			BUYOUT_PRICE / 2
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_ID3), 0);
+		assert_eq!(KittiesModule::buyout_offer(KITTY_ID).unwrap().buyer, ACCOUNT_ID2);
+	});
+}
+
+#[test]
+fn buyout_is_cancelled_when_buyer_owns_too_many_kitties() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SHARES));
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+		for _ in 0..MAX_KITTIES_OWNED {
+			assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_NAME));
+		}
+
+		// 买家无法再拥有 kitty，达到门槛时报价被取消，投票的份额退还
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		System::assert_last_event(
+			Event::BuyoutCancelled { who: ACCOUNT_ID3, kitty_id: KITTY_ID }.into(),
+		);
+		assert!(KittiesModule::buyout_offer(KITTY_ID).is_none());
+		assert!(KittiesModule::fractions(KITTY_ID).is_some());
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID), SHARES);
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(*PALLET_ACCOUNT_ID));
+		assert_eq!(
+			Balances::reserved_balance(ACCOUNT_ID3),
+			KittyPrice::get() * MAX_KITTIES_OWNED as u128
+		);
+	});
+}
+
+#[test]
+fn voters_without_balance_claim_after_buyout() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID, ACCOUNT_BALANCE, 0));
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID3, ACCOUNT_BALANCE, 0));
+		assert_ok!(KittiesModule::create(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_NAME));
+		assert_ok!(KittiesModule::fractionalize(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, SHARES));
+		assert_ok!(KittyShares::transfer(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID, ACCOUNT_ID2, 40));
+		assert_ok!(KittiesModule::offer_buyout(RuntimeOrigin::signed(ACCOUNT_ID3), KITTY_ID, BUYOUT_PRICE));
+
+		// 没有余额的投票者收不到付款，份额退还，买断照常完成
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_ok!(KittiesModule::vote_buyout(RuntimeOrigin::signed(ACCOUNT_ID), KITTY_ID));
+		assert_eq!(KittiesModule::kitty_owner(KITTY_ID), Some(ACCOUNT_ID3));
+		assert_eq!(KittyShares::balance(KITTY_ID, ACCOUNT_ID2), 40);
+		assert_eq!(
+			KittiesModule::buyout_claim(KITTY_ID),
+			Some(BuyoutClaim { buyer: ACCOUNT_ID3, price: 400, shares: 40 })
+		);
+
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), ACCOUNT_ID2, ACCOUNT_BALANCE2, 0));
+		assert_ok!(KittiesModule::claim_buyout(RuntimeOrigin::signed(ACCOUNT_ID2), KITTY_ID));
+		assert_eq!(Balances::free_balance(ACCOUNT_ID2), ACCOUNT_BALANCE2 + 400);
+		assert!(KittiesModule::buyout_claim(KITTY_ID).is_none());
 	});
 }
@@ -0,0 +1,1356 @@
+//! Placeholder weights for pallet_kitties
+//!
+//! These are NOT benchmark results: the benchmark CLI was not run when they were written. The
+//! base execution times are rounded guesses, while the database reads, writes and proof sizes
+//! follow the storage items each call accesses. Regenerate this file from `benchmarking.rs` on
+//! reference hardware before relying on it:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --execution wasm \
+//!     --wasm-execution compiled --pallet pallet_kitties --extrinsic '*' --steps 20 --repeat 10 \
+//!     --output ./pallets/kitties/src/weights.rs --template .maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_kitties.
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn breed() -> Weight;
+	fn transfer() -> Weight;
+	fn sale() -> Weight;
+	fn buy() -> Weight;
+	fn update_price() -> Weight;
+	fn cancel_sale() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auctions(a: u32, ) -> Weight;
+	fn burn() -> Weight;
+	fn sweep_pallet_account() -> Weight;
+	fn offer_siring() -> Weight;
+	fn cancel_siring() -> Weight;
+	fn breed_with_sire() -> Weight;
+	fn commit_breed() -> Weight;
+	fn reveal_breed() -> Weight;
+	fn cancel_breed_commitment() -> Weight;
+	fn approve() -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn transfer_from() -> Weight;
+	fn rename() -> Weight;
+	fn lend() -> Weight;
+	fn cancel_lend() -> Weight;
+	fn borrow() -> Weight;
+	fn fractionalize() -> Weight;
+	fn redeem() -> Weight;
+	fn offer_buyout(v: u32, ) -> Weight;
+	fn cancel_buyout(v: u32, ) -> Weight;
+	fn vote_buyout(v: u32, ) -> Weight;
+	fn claim_buyout() -> Weight;
+	fn withdraw_buyout_vote(v: u32, ) -> Weight;
+}
+
+/// Weights for pallet_kitties using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	/// Proof: Babe AuthorVrfRandomness (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	/// Storage: Babe NextRandomness (r:1 w:0)
+	/// Proof: Babe NextRandomness (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Babe EpochStart (r:1 w:0)
+	/// Proof: Babe EpochStart (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:0 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn create() -> Weight {
+		Weight::from_parts(50_000_000, 15305)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:2 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:2 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:2 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:2 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:3)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	/// Proof: Babe AuthorVrfRandomness (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	/// Storage: Babe NextRandomness (r:1 w:0)
+	/// Proof: Babe NextRandomness (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Babe EpochStart (r:1 w:0)
+	/// Proof: Babe EpochStart (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn breed() -> Weight {
+		Weight::from_parts(75_000_000, 45501)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:0 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn transfer() -> Weight {
+		Weight::from_parts(65_000_000, 25025)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn sale() -> Weight {
+		Weight::from_parts(25_000_000, 12721)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn buy() -> Weight {
+		Weight::from_parts(85_000_000, 22372)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn update_price() -> Weight {
+		Weight::from_parts(20_000_000, 5038)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn cancel_sale() -> Weight {
+		Weight::from_parts(20_000_000, 5038)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:1)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule AuctionsEndingAt (r:1 w:1)
+	/// Proof: KittiesModule AuctionsEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	fn create_auction() -> Weight {
+		Weight::from_parts(30_000_000, 12946)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: KittiesModule Auctions (r:1 w:1)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule AuctionsEndingAt (r:1 w:1)
+	/// Proof: KittiesModule AuctionsEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		Weight::from_parts(95_000_000, 23900)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: KittiesModule AuctionsEndingAt (r:1 w:1)
+	/// Proof: KittiesModule AuctionsEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:64 w:64)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:64 w:64)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: System Account (r:128 w:128)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:128 w:128)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:64 w:64)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:64)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:64)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:64)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:64)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 64]`.
+	fn settle_auctions(a: u32, ) -> Weight {
+		Weight::from_parts(8_000_000, 2744)
+			.saturating_add(Weight::from_parts(75_000_000, 21156).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(a.into())))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Fractions (r:1 w:0)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:3 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:1 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:3 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutClaims (r:3 w:0)
+	/// Proof: KittiesModule BuyoutClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:3 w:0)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:3 w:3)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:0 w:1)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:0 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BreedCommitments (r:1 w:1)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:1 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn burn() -> Weight {
+		Weight::from_parts(90_000_000, 73562)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn sweep_pallet_account() -> Weight {
+		Weight::from_parts(40_000_000, 5206)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn offer_siring() -> Weight {
+		Weight::from_parts(20_000_000, 5074)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:1 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn cancel_siring() -> Weight {
+		Weight::from_parts(20_000_000, 5038)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:1 w:0)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:2 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:2 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:2 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:3)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	/// Proof: Babe AuthorVrfRandomness (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	/// Storage: Babe NextRandomness (r:1 w:0)
+	/// Proof: Babe NextRandomness (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Babe EpochStart (r:1 w:0)
+	/// Proof: Babe EpochStart (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn breed_with_sire() -> Weight {
+		Weight::from_parts(110_000_000, 48068)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: KittiesModule BreedCommitments (r:2 w:2)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:2 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:2 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:2 w:0)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:2)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule UnseededBreedBlocks (r:1 w:1)
+	/// Proof: KittiesModule UnseededBreedBlocks (max_values: Some(1), max_size: Some(2406), added: 2901, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:2 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn commit_breed() -> Weight {
+		Weight::from_parts(40_000_000, 38287)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: KittiesModule BreedCommitments (r:1 w:1)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BreedSeeds (r:1 w:0)
+	/// Proof: KittiesModule BreedSeeds (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:2 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:2 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:0 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:2 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:1)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:0 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn reveal_breed() -> Weight {
+		Weight::from_parts(70_000_000, 44023)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: KittiesModule BreedCommitments (r:1 w:1)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:0 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_breed_commitment() -> Weight {
+		Weight::from_parts(15_000_000, 2567)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OperatorApprovals (r:1 w:0)
+	/// Proof: KittiesModule OperatorApprovals (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn approve() -> Weight {
+		Weight::from_parts(20_000_000, 5098)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule OperatorApprovals (r:0 w:1)
+	/// Proof: KittiesModule OperatorApprovals (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn set_approval_for_all() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:1 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OperatorApprovals (r:1 w:0)
+	/// Proof: KittiesModule OperatorApprovals (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Fractions (r:1 w:0)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:0 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(70_000_000, 32666)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:1 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:2 w:2)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn rename() -> Weight {
+		Weight::from_parts(25_000_000, 10052)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn lend() -> Weight {
+		Weight::from_parts(25_000_000, 10202)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:1 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn cancel_lend() -> Weight {
+		Weight::from_parts(20_000_000, 5074)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule LoanOffers (r:1 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoansEndingAt (r:1 w:1)
+	/// Proof: KittiesModule LoansEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:0 w:1)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn borrow() -> Weight {
+		Weight::from_parts(60_000_000, 18152)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Fractions (r:0 w:1)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn fractionalize() -> Weight {
+		Weight::from_parts(60_000_000, 19013)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: KittiesModule Fractions (r:1 w:1)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Metadata (r:1 w:1)
+	/// Proof: KittyShares Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		Weight::from_parts(115_000_000, 32404)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: KittiesModule Fractions (r:1 w:0)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:0)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 64]`.
+	fn offer_buyout(v: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 24675)
+			.saturating_add(Weight::from_parts(20_000_000, 2577).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 64]`.
+	fn cancel_buyout(v: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 15980)
+			.saturating_add(Weight::from_parts(20_000_000, 2577).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	/// Storage: KittiesModule Fractions (r:1 w:1)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:2 w:2)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutClaims (r:0 w:1)
+	/// Proof: KittiesModule BuyoutClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 64]`.
+	fn vote_buyout(v: u32, ) -> Weight {
+		Weight::from_parts(120_000_000, 29763)
+			.saturating_add(Weight::from_parts(25_000_000, 2603).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	/// Storage: KittiesModule BuyoutClaims (r:1 w:1)
+	/// Proof: KittiesModule BuyoutClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Metadata (r:1 w:1)
+	/// Proof: KittyShares Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn claim_buyout() -> Weight {
+		Weight::from_parts(80_000_000, 18161)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:2 w:2)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 64]`.
+	fn withdraw_buyout_vote(v: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 13407)
+			.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	/// Proof: Babe AuthorVrfRandomness (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	/// Storage: Babe NextRandomness (r:1 w:0)
+	/// Proof: Babe NextRandomness (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Babe EpochStart (r:1 w:0)
+	/// Proof: Babe EpochStart (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:0 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn create() -> Weight {
+		Weight::from_parts(50_000_000, 15305)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:2 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:2 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:2 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:2 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:3)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	/// Proof: Babe AuthorVrfRandomness (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	/// Storage: Babe NextRandomness (r:1 w:0)
+	/// Proof: Babe NextRandomness (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Babe EpochStart (r:1 w:0)
+	/// Proof: Babe EpochStart (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn breed() -> Weight {
+		Weight::from_parts(75_000_000, 45501)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:0 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn transfer() -> Weight {
+		Weight::from_parts(65_000_000, 25025)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn sale() -> Weight {
+		Weight::from_parts(25_000_000, 12721)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn buy() -> Weight {
+		Weight::from_parts(85_000_000, 22372)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn update_price() -> Weight {
+		Weight::from_parts(20_000_000, 5038)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn cancel_sale() -> Weight {
+		Weight::from_parts(20_000_000, 5038)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:1)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule AuctionsEndingAt (r:1 w:1)
+	/// Proof: KittiesModule AuctionsEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	fn create_auction() -> Weight {
+		Weight::from_parts(30_000_000, 12946)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: KittiesModule Auctions (r:1 w:1)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule AuctionsEndingAt (r:1 w:1)
+	/// Proof: KittiesModule AuctionsEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		Weight::from_parts(95_000_000, 23900)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: KittiesModule AuctionsEndingAt (r:1 w:1)
+	/// Proof: KittiesModule AuctionsEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:64 w:64)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:64 w:64)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: System Account (r:128 w:128)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:128 w:128)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:64 w:64)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:64)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:64)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:64)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:64)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 64]`.
+	fn settle_auctions(a: u32, ) -> Weight {
+		Weight::from_parts(8_000_000, 2744)
+			.saturating_add(Weight::from_parts(75_000_000, 21156).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(a.into())))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Fractions (r:1 w:0)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:3 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:1 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:3 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutClaims (r:3 w:0)
+	/// Proof: KittiesModule BuyoutClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:3 w:0)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:3 w:3)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:0 w:1)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:0 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BreedCommitments (r:1 w:1)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:1 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn burn() -> Weight {
+		Weight::from_parts(90_000_000, 73562)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn sweep_pallet_account() -> Weight {
+		Weight::from_parts(40_000_000, 5206)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn offer_siring() -> Weight {
+		Weight::from_parts(20_000_000, 5074)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:1 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn cancel_siring() -> Weight {
+		Weight::from_parts(20_000_000, 5038)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:1 w:0)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:2 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:2 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:2 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:3)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Babe AuthorVrfRandomness (r:1 w:0)
+	/// Proof: Babe AuthorVrfRandomness (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	/// Storage: Babe NextRandomness (r:1 w:0)
+	/// Proof: Babe NextRandomness (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Babe EpochStart (r:1 w:0)
+	/// Proof: Babe EpochStart (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn breed_with_sire() -> Weight {
+		Weight::from_parts(110_000_000, 48068)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: KittiesModule BreedCommitments (r:2 w:2)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:2 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:2 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:2 w:0)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:2)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule UnseededBreedBlocks (r:1 w:1)
+	/// Proof: KittiesModule UnseededBreedBlocks (max_values: Some(1), max_size: Some(2406), added: 2901, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:2 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn commit_breed() -> Weight {
+		Weight::from_parts(40_000_000, 38287)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: KittiesModule BreedCommitments (r:1 w:1)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BreedSeeds (r:1 w:0)
+	/// Proof: KittiesModule BreedSeeds (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:2 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:2 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:2 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule FreeKittyIds (r:1 w:1)
+	/// Proof: KittiesModule FreeKittyIds (max_values: Some(1), max_size: Some(4098), added: 4593, mode: MaxEncodedLen)
+	/// Storage: KittiesModule NextKittyId (r:1 w:1)
+	/// Proof: KittiesModule NextKittyId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:1 w:1)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:0 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyParents (r:0 w:1)
+	/// Proof: KittiesModule KittyParents (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyChildren (r:2 w:2)
+	/// Proof: KittiesModule KittyChildren (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyBreeding (r:2 w:1)
+	/// Proof: KittiesModule KittyBreeding (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:0 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn reveal_breed() -> Weight {
+		Weight::from_parts(70_000_000, 44023)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: KittiesModule BreedCommitments (r:1 w:1)
+	/// Proof: KittiesModule BreedCommitments (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: KittiesModule CommittedKitties (r:0 w:2)
+	/// Proof: KittiesModule CommittedKitties (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_breed_commitment() -> Weight {
+		Weight::from_parts(15_000_000, 2567)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OperatorApprovals (r:1 w:0)
+	/// Proof: KittiesModule OperatorApprovals (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn approve() -> Weight {
+		Weight::from_parts(20_000_000, 5098)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule OperatorApprovals (r:0 w:1)
+	/// Proof: KittiesModule OperatorApprovals (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn set_approval_for_all() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:1 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OperatorApprovals (r:1 w:0)
+	/// Proof: KittiesModule OperatorApprovals (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Fractions (r:1 w:0)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:2 w:2)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:0 w:1)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(70_000_000, 32666)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:1 w:1)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyByName (r:2 w:2)
+	/// Proof: KittiesModule KittyByName (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn rename() -> Weight {
+		Weight::from_parts(25_000_000, 10052)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn lend() -> Weight {
+		Weight::from_parts(25_000_000, 10202)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:1 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn cancel_lend() -> Weight {
+		Weight::from_parts(20_000_000, 5074)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: KittiesModule LoanOffers (r:1 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:1 w:0)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoansEndingAt (r:1 w:1)
+	/// Proof: KittiesModule LoansEndingAt (max_values: None, max_size: Some(269), added: 2744, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:0 w:1)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn borrow() -> Weight {
+		Weight::from_parts(60_000_000, 18152)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: KittiesModule KittyOwner (r:1 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOnSale (r:1 w:0)
+	/// Proof: KittiesModule KittyOnSale (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Auctions (r:1 w:0)
+	/// Proof: KittiesModule Auctions (max_values: None, max_size: Some(142), added: 2617, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyLoans (r:1 w:0)
+	/// Proof: KittiesModule KittyLoans (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule SiringOffers (r:0 w:1)
+	/// Proof: KittiesModule SiringOffers (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule LoanOffers (r:0 w:1)
+	/// Proof: KittiesModule LoanOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyApprovals (r:0 w:1)
+	/// Proof: KittiesModule KittyApprovals (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Fractions (r:0 w:1)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn fractionalize() -> Weight {
+		Weight::from_parts(60_000_000, 19013)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: KittiesModule Fractions (r:1 w:1)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Metadata (r:1 w:1)
+	/// Proof: KittyShares Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		Weight::from_parts(115_000_000, 32404)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: KittiesModule Fractions (r:1 w:0)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:0)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 64]`.
+	fn offer_buyout(v: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 24675)
+			.saturating_add(Weight::from_parts(20_000_000, 2577).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `v` is `[0, 64]`.
+	fn cancel_buyout(v: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 15980)
+			.saturating_add(Weight::from_parts(20_000_000, 2577).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	/// Storage: KittiesModule Fractions (r:1 w:1)
+	/// Proof: KittiesModule Fractions (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutOffers (r:1 w:1)
+	/// Proof: KittiesModule BuyoutOffers (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:2 w:2)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittiesModule OwnedKitties (r:1 w:1)
+	/// Proof: KittiesModule OwnedKitties (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyDeposits (r:1 w:1)
+	/// Proof: KittiesModule KittyDeposits (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: KittiesModule KittyOwner (r:0 w:1)
+	/// Proof: KittiesModule KittyOwner (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: KittiesModule BuyoutClaims (r:0 w:1)
+	/// Proof: KittiesModule BuyoutClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 64]`.
+	fn vote_buyout(v: u32, ) -> Weight {
+		Weight::from_parts(120_000_000, 29763)
+			.saturating_add(Weight::from_parts(25_000_000, 2603).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	/// Storage: KittiesModule BuyoutClaims (r:1 w:1)
+	/// Proof: KittiesModule BuyoutClaims (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:1 w:1)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Metadata (r:1 w:1)
+	/// Proof: KittyShares Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: KittiesModule Kitties (r:1 w:0)
+	/// Proof: KittiesModule Kitties (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn claim_buyout() -> Weight {
+		Weight::from_parts(80_000_000, 18161)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: KittiesModule BuyoutVotes (r:1 w:1)
+	/// Proof: KittiesModule BuyoutVotes (max_values: None, max_size: Some(3093), added: 5568, mode: MaxEncodedLen)
+	/// Storage: KittyShares Asset (r:1 w:1)
+	/// Proof: KittyShares Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: KittyShares Account (r:2 w:2)
+	/// Proof: KittyShares Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 64]`.
+	fn withdraw_buyout_vote(v: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 13407)
+			.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
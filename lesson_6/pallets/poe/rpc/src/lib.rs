@@ -0,0 +1,73 @@
+//! RPC interface for the proof of existence pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_poe_runtime_api::PoeApi as PoeRuntimeApi;
+
+#[rpc(client, server)]
+pub trait PoeApi<BlockHash, AccountId> {
+	/// All claims currently owned by `owner`.
+	#[method(name = "poe_claimsOf")]
+	fn claims_of(&self, owner: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<Bytes>>;
+}
+
+/// Provides RPC methods to query the proof of existence pallet.
+pub struct Poe<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Poe<C, B> {
+	/// Create a new `Poe` instance with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId> PoeApiServer<<Block as BlockT>::Hash, AccountId> for Poe<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PoeRuntimeApi<Block, AccountId, <Block as BlockT>::Hash>,
+	AccountId: Codec,
+{
+	fn claims_of(&self, owner: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<Bytes>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let claims = api.claims_of(at_hash, owner).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query claims.",
+				Some(e.to_string()),
+			))
+		})?;
+
+		Ok(claims.into_iter().map(Into::into).collect())
+	}
+}
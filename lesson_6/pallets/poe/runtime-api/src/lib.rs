@@ -0,0 +1,19 @@
+//! Runtime API definition for the proof of existence pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PoeApi<AccountId, Hash> where
+		AccountId: Codec,
+		Hash: Codec,
+	{
+		/// All claims currently owned by `owner`.
+		fn claims_of(owner: AccountId) -> Vec<Vec<u8>>;
+
+		/// Check that `root` is an anchored claim and that `proof` links `leaf` to it.
+		fn verify_inclusion(root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool;
+	}
+}
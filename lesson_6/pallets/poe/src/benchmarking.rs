@@ -1,39 +1,234 @@
 use crate::*;
-use frame_benchmarking::{benchmarks, whitelisted_caller, account};
+use frame_benchmarking::{benchmarks, whitelisted_caller, account, BenchmarkError};
+use frame_support::traits::{Currency, EnsureOrigin, ReservableCurrency};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash, One};
 use sp_std::vec;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+fn fund_account<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn expiry<T: Config>(blocks: u32) -> T::BlockNumber {
+	frame_system::Pallet::<T>::block_number() + blocks.into()
+}
+
+// MIME 类型和描述一共 `len` 个字节的元数据，先填满 MIME 类型
+fn metadata_of_len<T: Config>(len: u32) -> ClaimMetadataOf<T> {
+	let mime_len = len.min(T::MaxMimeTypeLength::get());
+	ClaimMetadata {
+		mime_type: BoundedVec::try_from(vec![b'a'; mime_len as usize]).unwrap(),
+		hash_algorithm: HashAlgorithm::Blake2_256,
+		description: BoundedVec::try_from(vec![b'a'; (len - mime_len) as usize]).unwrap(),
+	}
+}
+
+fn max_metadata<T: Config>() -> ClaimMetadataOf<T> {
+	ClaimMetadata {
+		mime_type: BoundedVec::try_from(vec![b'a'; T::MaxMimeTypeLength::get() as usize]).unwrap(),
+		hash_algorithm: HashAlgorithm::Blake2_256,
+		description: BoundedVec::try_from(vec![b'a'; T::MaxDescriptionLength::get() as usize]).unwrap(),
+	}
+}
+
 benchmarks! {
 	create_claim {
 		let d in 0 .. T::MaxClaimLength::get();
 		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
+		fund_account::<T>(&caller);
+		let metadata = Some(max_metadata::<T>());
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone(), Some(expiry::<T>(10)), metadata.clone())
 	verify {
-		assert_last_event::<T>(Event::ClaimCreated(caller, claim).into())
+		assert_last_event::<T>(Event::ClaimCreated(caller, claim, metadata).into())
 	}
 
 	revoke_claim {
 		let d in 0 .. T::MaxClaimLength::get();
 		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
 		let caller: T::AccountId = whitelisted_caller();
-		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone()).is_ok());
+		fund_account::<T>(&caller);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), Some(expiry::<T>(10)), None).is_ok());
 	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
 	verify {
 		assert_last_event::<T>(Event::ClaimRevoked(caller, claim).into())
 	}
 
 	transfer_claim {
+		let d in 0 .. T::MaxClaimLength::get();
+		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
+		let origin = T::TransferOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::TransferOrigin::ensure_origin(origin.clone()).unwrap();
+		fund_account::<T>(&caller);
+		let target: T::AccountId = account("target", 0, 0);
+		fund_account::<T>(&target);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), None, None).is_ok());
+	}: _<T::RuntimeOrigin>(origin, claim.clone(), target.clone())
+	verify {
+		assert_eq!(T::Currency::reserved_balance(&target), Pallet::<T>::claim_deposit(d as usize));
+	}
+
+	extend_claim {
+		let d in 0 .. T::MaxClaimLength::get();
+		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), Some(expiry::<T>(10)), None).is_ok());
+		let expires_at = Some(expiry::<T>(20));
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone(), expires_at)
+	verify {
+		assert_last_event::<T>(Event::ClaimExtended(caller, claim, expires_at).into())
+	}
+
+	prune_expired_claim {
+		let claim: BoundedVec<u8, T::MaxClaimLength> =
+			BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let expires_at = expiry::<T>(1);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), Some(expires_at), None).is_ok());
+		frame_system::Pallet::<T>::set_block_number(expires_at + One::one());
+	}: {
+		Pallet::<T>::expire_claim(expires_at, &claim);
+	}
+	verify {
+		assert!(!Proofs::<T>::contains_key(&claim));
+		assert_last_event::<T>(Event::ClaimExpired(caller, claim).into())
+	}
+
+	create_claims {
+		let b in 1 .. T::MaxBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatch> = (0..b)
+			.map(|i| {
+				let mut claim = i.encode();
+				claim.resize(T::MaxClaimLength::get() as usize, 0);
+				BoundedVec::try_from(claim).unwrap()
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let last = claims.last().cloned().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), claims, Some(expiry::<T>(10)), BatchMode::AllOrNothing)
+	verify {
+		assert_last_event::<T>(Event::ClaimCreated(caller, last, None).into())
+	}
+
+	create_root_claim {
+		let root = T::Hashing::hash(b"root");
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), root, Some(expiry::<T>(10)))
+	verify {
+		assert_last_event::<T>(Event::RootClaimCreated(caller, root).into())
+	}
+
+	create_joint_claim {
+		let o in 1 .. T::MaxOwners::get() - 1;
+		let claim: BoundedVec<u8, T::MaxClaimLength> =
+			BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let co_owners: OwnersOf<T> = (0..o)
+			.map(|i| account("owner", i, 0))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone(), co_owners.clone(), o + 1, Some(expiry::<T>(10)))
+	verify {
+		assert_last_event::<T>(Event::JointClaimCreated(caller, claim, co_owners, o + 1).into())
+	}
+
+	approve_claim_action {
+		let o in 1 .. T::MaxOwners::get() - 1;
+		let claim: BoundedVec<u8, T::MaxClaimLength> =
+			BoundedVec::try_from(vec![0; T::MaxClaimLength::get() as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let target: T::AccountId = account("target", 0, 0);
+		fund_account::<T>(&target);
+		let co_owners: OwnersOf<T> = (0..o)
+			.map(|i| account("owner", i, 0))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		assert!(Pallet::<T>::create_joint_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			claim.clone(),
+			co_owners.clone(),
+			o + 1,
+			Some(expiry::<T>(10)),
+		).is_ok());
+		// 除创建者外的所有者先批准，最后一个批准触发转移
+		for owner in co_owners.iter() {
+			assert!(Pallet::<T>::approve_claim_action(
+				RawOrigin::Signed(owner.clone()).into(),
+				claim.clone(),
+				ClaimAction::Transfer(target.clone()),
+			).is_ok());
+		}
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone(), ClaimAction::Transfer(target.clone()))
+	verify {
+		assert!(!PendingActions::<T>::contains_key(&claim));
+		assert_last_event::<T>(Event::ClaimOffered(caller, target, claim, expiry::<T>(0) + T::OfferTimeout::get()).into())
+	}
+
+	update_metadata {
+		let d in 0 .. T::MaxClaimLength::get();
+		let m in 0 .. T::MaxMimeTypeLength::get() + T::MaxDescriptionLength::get();
+		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), None, None).is_ok());
+		let metadata = Some(metadata_of_len::<T>(m));
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone(), metadata.clone())
+	verify {
+		assert_last_event::<T>(Event::ClaimMetadataUpdated(caller, claim, metadata).into())
+	}
+
+	offer_claim {
 		let d in 0 .. T::MaxClaimLength::get();
 		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
 		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
 		let target: T::AccountId = account("target", 0, 0);
-		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone()).is_ok());
-	}: _(RawOrigin::Signed(caller), claim, target)
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), None, None).is_ok());
+	}: _(RawOrigin::Signed(caller), claim.clone(), target.clone())
+	verify {
+		assert_eq!(PendingOffers::<T>::get(&claim).map(|offer| offer.dest), Some(target));
+	}
+
+	accept_claim {
+		let d in 0 .. T::MaxClaimLength::get();
+		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let target: T::AccountId = account("target", 0, 0);
+		fund_account::<T>(&target);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), None, None).is_ok());
+		assert!(Pallet::<T>::offer_claim(RawOrigin::Signed(caller).into(), claim.clone(), target.clone()).is_ok());
+	}: _(RawOrigin::Signed(target.clone()), claim.clone())
+	verify {
+		assert_eq!(T::Currency::reserved_balance(&target), Pallet::<T>::claim_deposit(d as usize));
+	}
+
+	cancel_offer {
+		let d in 0 .. T::MaxClaimLength::get();
+		let claim = BoundedVec::try_from(vec![0; d as usize]).unwrap();
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let target: T::AccountId = account("target", 0, 0);
+		assert!(Pallet::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), None, None).is_ok());
+		assert!(Pallet::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), claim.clone(), target).is_ok());
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
+	verify {
+		assert_last_event::<T>(Event::ClaimOfferCancelled(caller, claim).into())
+	}
 
 	impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -12,6 +12,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod merkle;
+pub mod migrations;
 pub mod weights;
 
 #[frame_support::pallet]
@@ -21,6 +23,115 @@ pub mod pallet {
 	pub use sp_std::prelude::*;
 	pub use super::WeightInfo;
 
+	use super::{merkle, migrations};
+	use frame_support::traits::{BalanceStatus, Currency, ReservableCurrency, StorageVersion};
+	use sp_runtime::traits::{One, Saturating, Zero};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type OwnersOf<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxOwners>;
+	pub type ClaimInfoOf<T> = ClaimInfo<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BalanceOf<T>,
+		OwnersOf<T>,
+		ClaimMetadataOf<T>,
+	>;
+	pub type ClaimMetadataOf<T> = ClaimMetadata<
+		BoundedVec<u8, <T as Config>::MaxMimeTypeLength>,
+		BoundedVec<u8, <T as Config>::MaxDescriptionLength>,
+	>;
+	pub type ClaimOfferOf<T> =
+		ClaimOffer<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+	pub type ClaimActionOf<T> =
+		ClaimAction<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+	pub type PendingActionOf<T> = PendingAction<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		OwnersOf<T>,
+	>;
+
+	/// 存证的详细信息
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimInfo<AccountId, BlockNumber, Balance, Owners, Metadata> {
+		/// The current owner of the claim.
+		pub owner: AccountId,
+		/// The block at which the current owner created or received the claim.
+		pub registered_at: BlockNumber,
+		/// The block from which the claim is no longer valid, if any.
+		pub expires_at: Option<BlockNumber>,
+		/// The deposit reserved from the owner for this claim.
+		pub deposit: Balance,
+		/// The other owners of a jointly owned claim.
+		pub co_owners: Owners,
+		/// The number of owner approvals needed to revoke or transfer the claim.
+		pub threshold: u32,
+		/// What the claimed content is and how it was hashed, if the owner told us.
+		pub metadata: Option<Metadata>,
+	}
+
+	/// 计算存证所用的哈希算法
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum HashAlgorithm {
+		Blake2_256,
+		Sha2_256,
+		Sha3_256,
+		Keccak256,
+	}
+
+	/// 存证内容的描述信息
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimMetadata<MimeType, Description> {
+		/// The MIME type of the claimed content, e.g. `application/pdf`.
+		pub mime_type: MimeType,
+		/// The algorithm used to hash the content into the claim.
+		pub hash_algorithm: HashAlgorithm,
+		/// A short human readable description of the content.
+		pub description: Description,
+	}
+
+	/// 共有存证需要多个所有者批准的操作
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ClaimAction<AccountId, BlockNumber> {
+		/// Revoke the claim and release its deposit.
+		Revoke,
+		/// Transfer the claim to a new, sole owner.
+		Transfer(AccountId),
+		/// Move the expiry of the claim to a later block, or drop it with `None`.
+		Extend(Option<BlockNumber>),
+	}
+
+	/// 等待所有者批准的操作
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingAction<AccountId, BlockNumber, Approvals> {
+		/// The action that will be executed once enough owners approve it.
+		pub action: ClaimAction<AccountId, BlockNumber>,
+		/// The owners who approved the action so far.
+		pub approvals: Approvals,
+		/// The block from which the approvals are discarded.
+		pub expires_at: BlockNumber,
+	}
+
+	/// 等待接收方确认的存证转移
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimOffer<AccountId, BlockNumber> {
+		/// The account the claim is offered to.
+		pub dest: AccountId,
+		/// The block from which the offer can no longer be accepted.
+		pub expires_at: BlockNumber,
+	}
+
+	/// 批量创建存证时遇到已存在存证的处理方式
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum BatchMode {
+		/// Fail the whole batch if any claim already exists.
+		AllOrNothing,
+		/// Create the claims that do not exist yet and report the rest.
+		BestEffort,
+	}
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
 	// 通过继承拥有了 frame_system::Config 里定义的数据类型
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -31,11 +142,50 @@ pub mod pallet {
 
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The currency in which claim deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The base deposit reserved for every claim.
+		#[pallet::constant]
+		type ClaimDepositBase: Get<BalanceOf<Self>>;
+
+		/// The additional deposit reserved per byte of claim.
+		#[pallet::constant]
+		type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum number of claims that can be created in one batch.
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+
+		/// The maximum number of owners of a jointly owned claim, including its creator.
+		#[pallet::constant]
+		type MaxOwners: Get<u32>;
+
+		/// The number of blocks after which a pending claim action is discarded.
+		#[pallet::constant]
+		type ActionTimeout: Get<Self::BlockNumber>;
+
+		/// The maximum length of a claim's MIME type.
+		#[pallet::constant]
+		type MaxMimeTypeLength: Get<u32>;
+
+		/// The maximum length of a claim's description.
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// The number of blocks during which a claim offer can be accepted.
+		#[pallet::constant]
+		type OfferTimeout: Get<Self::BlockNumber>;
+
+		/// The origin allowed to transfer claims without the recipient's acceptance.
+		type TransferOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
 		/// weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// 定义存储项
@@ -45,17 +195,71 @@ pub mod pallet {
 		// 密码安全的hash算法
 		Blake2_128Concat,
 		BoundedVec<u8, T::MaxClaimLength>,
-		(T::AccountId, T::BlockNumber),
+		ClaimInfoOf<T>,
+	>;
+
+	// 按过期区块索引的存证，用于在 on_idle 中清理
+	#[pallet::storage]
+	pub type ExpiringClaims<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxClaimLength>,
+		(),
 	>;
 
+	// 按所有者索引的存证
+	#[pallet::storage]
+	pub type ClaimsByOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxClaimLength>,
+		(),
+	>;
+
+	// 共有存证等待批准的操作
+	#[pallet::storage]
+	pub type PendingActions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxClaimLength>,
+		PendingActionOf<T>,
+	>;
+
+	// 等待接收方确认的存证转移
+	#[pallet::storage]
+	pub type PendingOffers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxClaimLength>,
+		ClaimOfferOf<T>,
+	>;
+
+	// 下一个需要检查过期存证的区块
+	#[pallet::storage]
+	pub type PruneCursor<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
 	// 定义事件
 	#[pallet::event]
 	// 生成工具函数
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, Option<ClaimMetadataOf<T>>),
 		ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
 		ClaimTransfered(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		ClaimExtended(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, Option<T::BlockNumber>),
+		ClaimExpired(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		ClaimsSkipped(T::AccountId, BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatch>),
+		RootClaimCreated(T::AccountId, T::Hash),
+		JointClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, OwnersOf<T>, u32),
+		ClaimActionApproved(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, ClaimActionOf<T>, u32),
+		ClaimActionExpired(BoundedVec<u8, T::MaxClaimLength>, ClaimActionOf<T>),
+		ClaimMetadataUpdated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, Option<ClaimMetadataOf<T>>),
+		ClaimOffered(T::AccountId, T::AccountId, BoundedVec<u8, T::MaxClaimLength>, T::BlockNumber),
+		ClaimOfferCancelled(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
 	}
 
 	// 定义错误
@@ -65,30 +269,52 @@ pub mod pallet {
 		ClaimTooLong,
 		ClaimNotExist,
 		NotClaimOwner,
+		InvalidExpiry,
+		ClaimExpired,
+		InvalidThreshold,
+		TooManyOwners,
+		DuplicateOwner,
+		ApprovalRequired,
+		AlreadyApproved,
+		ConflictingAction,
+		OfferNotExist,
+		NotOfferRecipient,
+		OfferExpired,
 	}
 
 	// 用于定义回调函数，在区块的不同时期执行
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_expired_claims(now, remaining_weight)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+				.saturating_add(migrations::v2::migrate::<T>())
+				.saturating_add(migrations::v3::migrate::<T>())
+				.saturating_add(migrations::v4::migrate::<T>())
+		}
+	}
 
 	// 定义可调用函数
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::create_claim(claim.len() as u32))]
-		pub fn create_claim(origin: OriginFor<T>, claim: BoundedVec<u8, T::MaxClaimLength>) -> DispatchResultWithPostInfo {
+		pub fn create_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			expires_at: Option<T::BlockNumber>,
+			metadata: Option<ClaimMetadataOf<T>>,
+		) -> DispatchResultWithPostInfo {
 			// 验证签名
 			let sender = ensure_signed(origin)?;
 
-			// 验证是否已经存储过
-			ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::ProofAlreadyExist);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at.map_or(true, |at| at > now), Error::<T>::InvalidExpiry);
 
-			Proofs::<T>::insert(
-				&claim,
-				(sender.clone(), frame_system::Pallet::<T>::block_number()),
-			);
-
-			Self::deposit_event(Event::ClaimCreated(sender, claim));
+			Self::do_create_claim(&sender, claim, Default::default(), 1, expires_at, metadata, now)?;
 
 			Ok(().into())
 		}
@@ -100,16 +326,15 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 
 			// 校验是否已经存在存证
-			let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+			let info = Self::live_claim(&claim)?;
 
 			// 验证存证的所有者是否是当前用户
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
 
-			// 从存储里删除存证
-			Proofs::<T>::remove(&claim);
+			// 共有存证需要通过 approve_claim_action 吊销
+			ensure!(info.threshold <= 1, Error::<T>::ApprovalRequired);
 
-			// 发送存证吊销事件
-			Self::deposit_event(Event::ClaimRevoked(sender, claim));
+			Self::do_revoke_claim(claim, info);
 
 			Ok(().into())
 		}
@@ -120,23 +345,551 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			claim: BoundedVec<u8, T::MaxClaimLength>,
 			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 未经接收方确认的直接转移只对特定的调用者开放
+			let sender = T::TransferOrigin::ensure_origin(origin)?;
+
+			// 校验是否已经存在存证
+			let info = Self::live_claim(&claim)?;
+
+			// 验证存证的所有者是否是当前用户
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+			// 共有存证需要通过 approve_claim_action 转移
+			ensure!(info.threshold <= 1, Error::<T>::ApprovalRequired);
+
+			Self::do_transfer_claim(claim, info, dest)?;
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::extend_claim(claim.len() as u32))]
+		pub fn extend_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			expires_at: Option<T::BlockNumber>,
 		) -> DispatchResultWithPostInfo {
 			// 验证签名
 			let sender = ensure_signed(origin)?;
 
 			// 校验是否已经存在存证
-			let (owner, _) = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+			let info = Self::live_claim(&claim)?;
 
 			// 验证存证的所有者是否是当前用户
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+			// 共有存证需要通过 approve_claim_action 续期
+			ensure!(info.threshold <= 1, Error::<T>::ApprovalRequired);
+
+			Self::do_extend_claim(claim, info, expires_at)?;
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+		pub fn create_claims(
+			origin: OriginFor<T>,
+			claims: BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatch>,
+			expires_at: Option<T::BlockNumber>,
+			mode: BatchMode,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at.map_or(true, |at| at > now), Error::<T>::InvalidExpiry);
+
+			let mut skipped = BoundedVec::<BoundedVec<u8, T::MaxClaimLength>, T::MaxBatch>::default();
+			for claim in claims {
+				match Self::do_create_claim(&sender, claim.clone(), Default::default(), 1, expires_at, None, now) {
+					Err(e) if mode == BatchMode::BestEffort &&
+						e == Error::<T>::ProofAlreadyExist.into() =>
+					{
+						// 批次长度不超过 MaxBatch，跳过的存证不会超出上限
+						let _ = skipped.try_push(claim);
+					},
+					result => result?,
+				}
+			}
 
+			if !skipped.is_empty() {
+				Self::deposit_event(Event::ClaimsSkipped(sender, skipped));
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_root_claim())]
+		pub fn create_root_claim(
+			origin: OriginFor<T>,
+			root: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at.map_or(true, |at| at > now), Error::<T>::InvalidExpiry);
+
+			// Merkle 根作为普通存证保存，之后可以照常吊销、转移和续期
+			let claim = Self::root_claim(&root)?;
+			Self::do_create_claim(&sender, claim, Default::default(), 1, expires_at, None, now)?;
+
+			Self::deposit_event(Event::RootClaimCreated(sender, root));
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_joint_claim(co_owners.len() as u32))]
+		pub fn create_joint_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			co_owners: OwnersOf<T>,
+			threshold: u32,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(expires_at.map_or(true, |at| at > now), Error::<T>::InvalidExpiry);
+
+			// 所有者总数包含创建者
+			let owners = co_owners.len() as u32 + 1;
+			ensure!(owners <= T::MaxOwners::get(), Error::<T>::TooManyOwners);
+			ensure!(threshold >= 1 && threshold <= owners, Error::<T>::InvalidThreshold);
+
+			let mut sorted = co_owners.to_vec();
+			sorted.push(sender.clone());
+			sorted.sort();
+			sorted.dedup();
+			ensure!(sorted.len() as u32 == owners, Error::<T>::DuplicateOwner);
+
+			Self::do_create_claim(&sender, claim.clone(), co_owners.clone(), threshold, expires_at, None, now)?;
+
+			Self::deposit_event(Event::JointClaimCreated(sender, claim, co_owners, threshold));
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::approve_claim_action(T::MaxOwners::get()))]
+		pub fn approve_claim_action(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			action: ClaimActionOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 校验是否已经存在存证
+			let info = Self::live_claim(&claim)?;
+
+			// 验证当前用户是否是存证的所有者之一
+			ensure!(
+				info.owner == sender || info.co_owners.contains(&sender),
+				Error::<T>::NotClaimOwner
+			);
+
+			// 超时的操作被丢弃，重新开始收集批准
+			let now = frame_system::Pallet::<T>::block_number();
+			let pending = match PendingActions::<T>::get(&claim) {
+				Some(pending) if pending.expires_at <= now => {
+					Self::deposit_event(Event::ClaimActionExpired(claim.clone(), pending.action));
+					None
+				},
+				pending => pending,
+			};
+			let mut pending = pending.unwrap_or_else(|| PendingAction {
+				action: action.clone(),
+				approvals: Default::default(),
+				expires_at: now.saturating_add(T::ActionTimeout::get()),
+			});
+
+			ensure!(pending.action == action, Error::<T>::ConflictingAction);
+			ensure!(!pending.approvals.contains(&sender), Error::<T>::AlreadyApproved);
+			if let ClaimAction::Extend(expires_at) = action {
+				Self::check_extension(&info, expires_at)?;
+			}
+			pending.approvals.try_push(sender.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+
+			let approvals = pending.approvals.len() as u32;
+			Self::deposit_event(Event::ClaimActionApproved(sender, claim.clone(), action.clone(), approvals));
+
+			if approvals < info.threshold {
+				PendingActions::<T>::insert(&claim, pending);
+				return Ok(().into())
+			}
+
+			// 批准数量达到阈值，执行操作；转移同样需要接收方确认
+			match action {
+				ClaimAction::Revoke => Self::do_revoke_claim(claim, info),
+				ClaimAction::Transfer(dest) => {
+					PendingActions::<T>::remove(&claim);
+					Self::do_offer_claim(info.owner, claim, dest, now);
+				},
+				ClaimAction::Extend(expires_at) => {
+					PendingActions::<T>::remove(&claim);
+					Self::do_extend_claim(claim, info, expires_at)?;
+				},
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::update_metadata(
+			claim.len() as u32,
+			metadata.as_ref().map_or(0, |m| (m.mime_type.len() + m.description.len()) as u32),
+		))]
+		pub fn update_metadata(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			metadata: Option<ClaimMetadataOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 校验是否已经存在存证
+			let mut info = Self::live_claim(&claim)?;
+
+			// 验证存证的所有者是否是当前用户
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+			// 共有存证的元数据不能由单个所有者修改
+			ensure!(info.threshold <= 1, Error::<T>::ApprovalRequired);
+
+			// 按新的元数据长度调整押金
+			let deposit = Self::claim_deposit(claim.len() + metadata.as_ref().map_or(0, |m| m.encoded_size()));
+			if deposit > info.deposit {
+				T::Currency::reserve(&sender, deposit.saturating_sub(info.deposit))?;
+			} else {
+				T::Currency::unreserve(&sender, info.deposit.saturating_sub(deposit));
+			}
+
+			info.deposit = deposit;
+			info.metadata = metadata.clone();
+			Proofs::<T>::insert(&claim, info);
+
+			Self::deposit_event(Event::ClaimMetadataUpdated(sender, claim, metadata));
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::offer_claim(claim.len() as u32))]
+		pub fn offer_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 校验是否已经存在存证
+			let info = Self::live_claim(&claim)?;
+
+			// 验证存证的所有者是否是当前用户
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+			// 共有存证需要通过 approve_claim_action 转移
+			ensure!(info.threshold <= 1, Error::<T>::ApprovalRequired);
+
+			// 新的转移请求会覆盖之前未被接受的请求
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::do_offer_claim(sender, claim, dest, now);
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::accept_claim(claim.len() as u32))]
+		pub fn accept_claim(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 校验是否存在发给当前用户且未超时的转移请求
+			let offer = PendingOffers::<T>::get(&claim).ok_or(Error::<T>::OfferNotExist)?;
+			ensure!(offer.dest == sender, Error::<T>::NotOfferRecipient);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(offer.expires_at > now, Error::<T>::OfferExpired);
+
+			let info = Self::live_claim(&claim)?;
+			Self::do_transfer_claim(claim, info, sender)?;
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::cancel_offer(claim.len() as u32))]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&claim).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证的所有者是否是当前用户
+			ensure!(info.owner == sender, Error::<T>::NotClaimOwner);
+
+			PendingOffers::<T>::take(&claim).ok_or(Error::<T>::OfferNotExist)?;
+
+			Self::deposit_event(Event::ClaimOfferCancelled(sender, claim));
+
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The deposit reserved for a claim and its metadata taking `len` bytes.
+		pub fn claim_deposit(len: usize) -> BalanceOf<T> {
+			T::ClaimDepositPerByte::get()
+				.saturating_mul((len as u32).into())
+				.saturating_add(T::ClaimDepositBase::get())
+		}
+
+		/// The claim under which a Merkle root is anchored in `Proofs`.
+		pub fn root_claim(root: &T::Hash) -> Result<BoundedVec<u8, T::MaxClaimLength>, DispatchError> {
+			BoundedVec::try_from(root.as_ref().to_vec()).map_err(|_| Error::<T>::ClaimTooLong.into())
+		}
+
+		/// Check that `root` is an anchored, unexpired claim and that `proof` links `leaf` to it.
+		pub fn verify_inclusion(root: T::Hash, leaf: T::Hash, proof: Vec<T::Hash>) -> bool {
+			let anchored = Self::root_claim(&root).map_or(false, |claim| Self::live_claim(&claim).is_ok());
+			anchored && merkle::verify_proof::<T::Hashing>(&root, &leaf, &proof)
+		}
+
+		/// All claims currently owned by `owner`, including expired ones not yet pruned.
+		pub fn claims_of(owner: T::AccountId) -> Vec<BoundedVec<u8, T::MaxClaimLength>> {
+			ClaimsByOwner::<T>::iter_key_prefix(owner).collect()
+		}
+
+		/// Reserve the deposit for `claim` and store it for `owner` and `co_owners`.
+		fn do_create_claim(
+			owner: &T::AccountId,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			co_owners: OwnersOf<T>,
+			threshold: u32,
+			expires_at: Option<T::BlockNumber>,
+			metadata: Option<ClaimMetadataOf<T>>,
+			now: T::BlockNumber,
+		) -> DispatchResult {
+			// 已过期但尚未清理的存证可以被重新创建
+			Self::prune_if_expired(&claim, now);
+
+			// 验证是否已经存储过
+			ensure!(!Proofs::<T>::contains_key(&claim), Error::<T>::ProofAlreadyExist);
+
+			// 按存证和元数据的长度预留押金
+			let deposit = Self::claim_deposit(claim.len() + metadata.as_ref().map_or(0, |m| m.encoded_size()));
+			T::Currency::reserve(owner, deposit)?;
+
+			if let Some(at) = expires_at {
+				ExpiringClaims::<T>::insert(at, &claim, ());
+			}
+
+			ClaimsByOwner::<T>::insert(owner, &claim, ());
+			for co_owner in co_owners.iter() {
+				ClaimsByOwner::<T>::insert(co_owner, &claim, ());
+			}
+
+			Proofs::<T>::insert(
+				&claim,
+				ClaimInfo {
+					owner: owner.clone(),
+					registered_at: now,
+					expires_at,
+					deposit,
+					co_owners,
+					threshold,
+					metadata: metadata.clone(),
+				},
+			);
+
+			Self::deposit_event(Event::ClaimCreated(owner.clone(), claim, metadata));
+
+			Ok(())
+		}
+
+		/// Remove `claim` with its indexes and pending action, releasing the deposit.
+		fn do_revoke_claim(claim: BoundedVec<u8, T::MaxClaimLength>, info: ClaimInfoOf<T>) {
 			// 从存储里删除存证
-			Proofs::<T>::insert(&claim, (dest, frame_system::Pallet::<T>::block_number()));
+			Self::remove_claim(&claim, &info);
+
+			// 退还押金
+			T::Currency::unreserve(&info.owner, info.deposit);
+
+			// 发送存证吊销事件
+			Self::deposit_event(Event::ClaimRevoked(info.owner, claim));
+		}
+
+		/// Check that `expires_at` extends the expiry of `info`, returning the current expiry.
+		fn check_extension(
+			info: &ClaimInfoOf<T>,
+			expires_at: Option<T::BlockNumber>,
+		) -> Result<T::BlockNumber, DispatchError> {
+			// 只能延长有效期, None 表示永久有效
+			let old_expiry = info.expires_at.ok_or(Error::<T>::InvalidExpiry)?;
+			ensure!(expires_at.map_or(true, |at| at > old_expiry), Error::<T>::InvalidExpiry);
+			Ok(old_expiry)
+		}
+
+		/// Move the expiry of `claim` to `expires_at`.
+		fn do_extend_claim(
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			mut info: ClaimInfoOf<T>,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let old_expiry = Self::check_extension(&info, expires_at)?;
+
+			ExpiringClaims::<T>::remove(old_expiry, &claim);
+			if let Some(at) = expires_at {
+				ExpiringClaims::<T>::insert(at, &claim, ());
+			}
+
+			let owner = info.owner.clone();
+			info.expires_at = expires_at;
+			Proofs::<T>::insert(&claim, info);
+
+			Self::deposit_event(Event::ClaimExtended(owner, claim, expires_at));
+
+			Ok(())
+		}
+
+		/// Offer `claim` to `dest`, who has `OfferTimeout` blocks to accept it.
+		fn do_offer_claim(
+			owner: T::AccountId,
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			dest: T::AccountId,
+			now: T::BlockNumber,
+		) {
+			let expires_at = now.saturating_add(T::OfferTimeout::get());
+			PendingOffers::<T>::insert(&claim, ClaimOffer { dest: dest.clone(), expires_at });
+
+			Self::deposit_event(Event::ClaimOffered(owner, dest, claim, expires_at));
+		}
+
+		/// Hand `claim` to `dest` as its sole owner, moving the deposit along.
+		fn do_transfer_claim(
+			claim: BoundedVec<u8, T::MaxClaimLength>,
+			info: ClaimInfoOf<T>,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			// 将押金转移给新的所有者
+			let mut deposit = info.deposit;
+			if !deposit.is_zero() {
+				let missing =
+					T::Currency::repatriate_reserved(&info.owner, &dest, deposit, BalanceStatus::Reserved)?;
+				deposit = deposit.saturating_sub(missing);
+			}
+
+			Self::remove_claim(&claim, &info);
+			ClaimsByOwner::<T>::insert(&dest, &claim, ());
+			if let Some(at) = info.expires_at {
+				ExpiringClaims::<T>::insert(at, &claim, ());
+			}
+
+			Proofs::<T>::insert(
+				&claim,
+				ClaimInfo {
+					owner: dest.clone(),
+					registered_at: frame_system::Pallet::<T>::block_number(),
+					expires_at: info.expires_at,
+					deposit,
+					co_owners: Default::default(),
+					threshold: 1,
+					metadata: info.metadata.clone(),
+				},
+			);
 
 			// 发送存证转移事件
-			Self::deposit_event(Event::ClaimTransfered(owner, sender, claim));
+			Self::deposit_event(Event::ClaimTransfered(info.owner, dest, claim));
 
-			Ok(().into())
+			Ok(())
+		}
+
+		/// Remove `claim` and everything indexed under it.
+		fn remove_claim(claim: &BoundedVec<u8, T::MaxClaimLength>, info: &ClaimInfoOf<T>) {
+			Proofs::<T>::remove(claim);
+			PendingActions::<T>::remove(claim);
+			PendingOffers::<T>::remove(claim);
+			ClaimsByOwner::<T>::remove(&info.owner, claim);
+			for co_owner in info.co_owners.iter() {
+				ClaimsByOwner::<T>::remove(co_owner, claim);
+			}
+			if let Some(at) = info.expires_at {
+				ExpiringClaims::<T>::remove(at, claim);
+			}
+		}
+
+		/// Fetch a claim, failing if it does not exist or has already expired.
+		fn live_claim(claim: &BoundedVec<u8, T::MaxClaimLength>) -> Result<ClaimInfoOf<T>, DispatchError> {
+			let info = Proofs::<T>::get(claim).ok_or(Error::<T>::ClaimNotExist)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(info.expires_at.map_or(true, |at| at > now), Error::<T>::ClaimExpired);
+			Ok(info)
+		}
+
+		/// Remove `claim` straight away if it expired but was not pruned yet.
+		fn prune_if_expired(claim: &BoundedVec<u8, T::MaxClaimLength>, now: T::BlockNumber) {
+			if let Some(at) = Proofs::<T>::get(claim).and_then(|info| info.expires_at) {
+				if at <= now {
+					Self::expire_claim(at, claim);
+				}
+			}
+		}
+
+		/// Remove an expired claim, release its deposit and emit `ClaimExpired`.
+		pub(crate) fn expire_claim(at: T::BlockNumber, claim: &BoundedVec<u8, T::MaxClaimLength>) {
+			ExpiringClaims::<T>::remove(at, claim);
+
+			match Proofs::<T>::get(claim) {
+				Some(info) if info.expires_at == Some(at) => {
+					Self::remove_claim(claim, &info);
+					T::Currency::unreserve(&info.owner, info.deposit);
+					Self::deposit_event(Event::ClaimExpired(info.owner, claim.clone()));
+				},
+				_ => {},
+			}
+		}
+
+		/// Walk the expiry queue up to `now`, pruning as many claims as `limit` allows.
+		pub(crate) fn prune_expired_claims(now: T::BlockNumber, limit: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			let prune_weight = T::WeightInfo::prune_expired_claim();
+			let step_weight = prune_weight.max(db.reads(1));
+
+			let mut used = db.reads_writes(1, 1);
+			if used.any_gt(limit) {
+				return Weight::zero()
+			}
+
+			let mut cursor = PruneCursor::<T>::get();
+			while cursor <= now && !used.saturating_add(step_weight).any_gt(limit) {
+				match ExpiringClaims::<T>::iter_key_prefix(cursor).next() {
+					Some(claim) => {
+						Self::expire_claim(cursor, &claim);
+						used.saturating_accrue(prune_weight);
+					},
+					None => {
+						cursor.saturating_accrue(One::one());
+						used.saturating_accrue(db.reads(1));
+					},
+				}
+			}
+
+			PruneCursor::<T>::put(cursor);
+			used
 		}
 	}
 }
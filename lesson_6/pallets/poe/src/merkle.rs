@@ -0,0 +1,30 @@
+//! 基于 Merkle 树的批量存证校验
+//!
+//! 叶子节点为 `hash(0x00 ++ leaf)`，内部节点为 `hash(0x01 ++ min(a, b) ++ max(a, b))`。
+//! 前缀用于区分叶子和内部节点；兄弟节点按字节序排序后再拼接，因此证明中不需要携带左右位置。
+
+use sp_runtime::traits::Hash;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Hash a document hash into a leaf node of the tree.
+pub fn leaf_hash<H: Hash>(leaf: &H::Output) -> H::Output {
+	H::hash(&[&[LEAF_PREFIX][..], leaf.as_ref()].concat())
+}
+
+/// Hash two sibling nodes into their parent node.
+pub fn node_hash<H: Hash>(a: &H::Output, b: &H::Output) -> H::Output {
+	let (left, right) = if a.as_ref() <= b.as_ref() { (a, b) } else { (b, a) };
+	H::hash(&[&[NODE_PREFIX][..], left.as_ref(), right.as_ref()].concat())
+}
+
+/// Fold `proof` over the leaf and return the resulting root.
+pub fn root_from_proof<H: Hash>(leaf: &H::Output, proof: &[H::Output]) -> H::Output {
+	proof.iter().fold(leaf_hash::<H>(leaf), |node, sibling| node_hash::<H>(&node, sibling))
+}
+
+/// Check that `proof` links `leaf` to `root`.
+pub fn verify_proof<H: Hash>(root: &H::Output, leaf: &H::Output, proof: &[H::Output]) -> bool {
+	root_from_proof::<H>(leaf, proof) == *root
+}
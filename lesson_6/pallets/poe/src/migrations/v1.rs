@@ -0,0 +1,62 @@
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+
+use crate::{BalanceOf, Config, Pallet, PruneCursor};
+
+/// 版本 1 的存证信息
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+	pub owner: AccountId,
+	pub registered_at: BlockNumber,
+	pub expires_at: Option<BlockNumber>,
+	pub deposit: Balance,
+}
+
+pub type ClaimInfoOf<T> =
+	ClaimInfo<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+
+/// 版本 1 的存证存储
+#[storage_alias]
+pub type Proofs<T: Config> = StorageMap<
+	Pallet<T>,
+	Blake2_128Concat,
+	BoundedVec<u8, <T as Config>::MaxClaimLength>,
+	ClaimInfoOf<T>,
+>;
+
+/// 升级前为存证单独保存的押金
+#[storage_alias]
+type ClaimDeposits<T: Config> = StorageMap<
+	Pallet<T>,
+	Blake2_128Concat,
+	BoundedVec<u8, <T as Config>::MaxClaimLength>,
+	BalanceOf<T>,
+	ValueQuery,
+>;
+
+/// 将 `(T::AccountId, T::BlockNumber)` 存证迁移为 `ClaimInfo`，并合并单独保存的押金。
+pub fn migrate<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() != 0 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut translated = 0u64;
+	Proofs::<T>::translate::<(T::AccountId, T::BlockNumber), _>(|claim, (owner, registered_at)| {
+		translated += 1;
+		let deposit = ClaimDeposits::<T>::take(&claim);
+		Some(ClaimInfo { owner, registered_at, expires_at: None, deposit })
+	});
+
+	// 之前的存证都不会过期，清理游标从当前区块开始
+	PruneCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	// 每个存证读写一次 Proofs 和 ClaimDeposits，另外读取一次版本并写入游标和版本
+	T::DbWeight::get().reads_writes(translated * 2 + 1, translated * 2 + 2)
+}
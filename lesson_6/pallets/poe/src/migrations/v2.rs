@@ -0,0 +1,26 @@
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+use super::v1;
+use crate::{ClaimsByOwner, Config, Pallet};
+
+/// 根据已有的存证构建 `ClaimsByOwner` 索引。
+pub fn migrate<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() != 1 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut indexed = 0u64;
+	for (claim, info) in v1::Proofs::<T>::iter() {
+		ClaimsByOwner::<T>::insert(&info.owner, &claim, ());
+		indexed += 1;
+	}
+
+	StorageVersion::new(2).put::<Pallet<T>>();
+
+	// 每个存证读取一次并写入一次索引，另外读取并写入一次版本
+	T::DbWeight::get().reads_writes(indexed + 1, indexed + 1)
+}
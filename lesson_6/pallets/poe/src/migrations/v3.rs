@@ -0,0 +1,62 @@
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+
+use super::v1;
+use crate::{BalanceOf, Config, OwnersOf, Pallet};
+
+/// 版本 3 的存证信息
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ClaimInfo<AccountId, BlockNumber, Balance, Owners> {
+	pub owner: AccountId,
+	pub registered_at: BlockNumber,
+	pub expires_at: Option<BlockNumber>,
+	pub deposit: Balance,
+	pub co_owners: Owners,
+	pub threshold: u32,
+}
+
+pub type ClaimInfoOf<T> = ClaimInfo<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	BalanceOf<T>,
+	OwnersOf<T>,
+>;
+
+/// 版本 3 的存证存储
+#[storage_alias]
+pub type Proofs<T: Config> = StorageMap<
+	Pallet<T>,
+	Blake2_128Concat,
+	BoundedVec<u8, <T as Config>::MaxClaimLength>,
+	ClaimInfoOf<T>,
+>;
+
+/// 为存证增加共有者和批准阈值，已有存证均为单一所有者。
+pub fn migrate<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() != 2 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut translated = 0u64;
+	Proofs::<T>::translate::<v1::ClaimInfoOf<T>, _>(|_, old| {
+		translated += 1;
+		Some(ClaimInfo {
+			owner: old.owner,
+			registered_at: old.registered_at,
+			expires_at: old.expires_at,
+			deposit: old.deposit,
+			co_owners: Default::default(),
+			threshold: 1,
+		})
+	});
+
+	StorageVersion::new(3).put::<Pallet<T>>();
+
+	// 每个存证读写一次，另外读取并写入一次版本
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
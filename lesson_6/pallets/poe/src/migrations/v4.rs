@@ -0,0 +1,33 @@
+use frame_support::{
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+use super::v3;
+use crate::{ClaimInfo, Config, Pallet, Proofs};
+
+/// 为存证增加可选的元数据，已有存证均没有元数据。
+pub fn migrate<T: Config>() -> Weight {
+	if Pallet::<T>::on_chain_storage_version() != 3 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut translated = 0u64;
+	Proofs::<T>::translate::<v3::ClaimInfoOf<T>, _>(|_, old| {
+		translated += 1;
+		Some(ClaimInfo {
+			owner: old.owner,
+			registered_at: old.registered_at,
+			expires_at: old.expires_at,
+			deposit: old.deposit,
+			co_owners: old.co_owners,
+			threshold: old.threshold,
+			metadata: None,
+		})
+	});
+
+	StorageVersion::new(4).put::<Pallet<T>>();
+
+	// 每个存证读写一次，另外读取并写入一次版本
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
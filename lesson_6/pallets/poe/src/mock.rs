@@ -1,5 +1,6 @@
 use crate as pallet_poe;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use frame_support::traits::{ConstU128, ConstU16, ConstU32, ConstU64, GenesisBuild, SortedMembers};
+use frame_system::EnsureSignedBy;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -9,6 +10,8 @@ use sp_runtime::{
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+pub type Balance = u128;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test where
@@ -18,6 +21,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		PoeModule: pallet_poe,
+		Balances: pallet_balances,
 	}
 );
 
@@ -39,7 +43,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -48,13 +52,55 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+pub const CLAIM_DEPOSIT_BASE: Balance = 10;
+pub const CLAIM_DEPOSIT_PER_BYTE: Balance = 1;
+pub const INITIAL_BALANCE: Balance = 1_000;
+pub const ACTION_TIMEOUT: u64 = 10;
+pub const OFFER_TIMEOUT: u64 = 20;
+
+// 可以直接转移存证的账户
+pub struct TransferAdmins;
+impl SortedMembers<u64> for TransferAdmins {
+	fn sorted_members() -> Vec<u64> {
+		vec![1, 2]
+	}
+}
+
 impl pallet_poe::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxClaimLength = ConstU32<32>;
+	type Currency = Balances;
+	type ClaimDepositBase = ConstU128<CLAIM_DEPOSIT_BASE>;
+	type ClaimDepositPerByte = ConstU128<CLAIM_DEPOSIT_PER_BYTE>;
+	type MaxBatch = ConstU32<10>;
+	type MaxOwners = ConstU32<3>;
+	type ActionTimeout = ConstU64<ACTION_TIMEOUT>;
+	type MaxMimeTypeLength = ConstU32<32>;
+	type MaxDescriptionLength = ConstU32<64>;
+	type OfferTimeout = ConstU64<OFFER_TIMEOUT>;
+	type TransferOrigin = EnsureSignedBy<TransferAdmins, u64>;
 	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, INITIAL_BALANCE), (2, INITIAL_BALANCE), (3, INITIAL_BALANCE)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
 }
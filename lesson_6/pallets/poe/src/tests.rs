@@ -1,15 +1,61 @@
-use crate::{mock::*, Error, Proofs};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{
+	merkle,
+	migrations::{v1, v3},
+	mock::*,
+	BatchMode, ClaimAction, ClaimInfo, ClaimMetadata, ClaimsByOwner, Error, Event, ExpiringClaims,
+	HashAlgorithm, PendingActions, PendingOffers, Proofs,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	pallet_prelude::*,
+	storage_alias,
+	traits::{Currency, GetStorageVersion, Hooks, StorageVersion},
+	BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
 
 #[test]
 fn create_claim_works() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
-		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 
 		assert_eq!(
 			Proofs::<Test>::get(&claim),
-			Some((1, frame_system::Pallet::<Test>::block_number()))
+			Some(ClaimInfo {
+				owner: 1,
+				registered_at: frame_system::Pallet::<Test>::block_number(),
+				expires_at: None,
+				deposit: CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE,
+				co_owners: Default::default(),
+				threshold: 1,
+				metadata: None,
+			})
+		);
+	});
+}
+
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		let deposit = CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+		assert_eq!(Balances::reserved_balance(1), deposit);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - deposit);
+	});
+}
+
+#[test]
+fn create_claim_failed_when_deposit_not_affordable() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(4), claim.clone(), None, None),
+			pallet_balances::Error::<Test>::InsufficientBalance
 		);
 	});
 }
@@ -18,10 +64,10 @@ fn create_claim_works() {
 fn create_claim_failed_when_claim_already_exist() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
-		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone());
+		let _ = PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None);
 
 		assert_noop!(
-			PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()),
+			PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None),
 			Error::<Test>::ProofAlreadyExist
 		);
 	});
@@ -31,9 +77,13 @@ fn create_claim_failed_when_claim_already_exist() {
 fn revoke_claim_works() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
-		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 
 		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+
+		assert_eq!(Proofs::<Test>::get(&claim), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
 	});
 }
 
@@ -53,7 +103,7 @@ fn revoke_claim_failed_when_claim_is_not_exist() {
 fn revoke_claim_failed_with_wrong_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
-		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 
 		assert_noop!(
 			PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()),
@@ -66,9 +116,114 @@ fn revoke_claim_failed_with_wrong_owner() {
 fn transfer_claim_works() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
-		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 
 		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+		let deposit = CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE;
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - deposit);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| (info.owner, info.deposit)), Some((2, deposit)));
+
+		// 新的所有者吊销存证后取回押金
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), INITIAL_BALANCE + deposit);
+	});
+}
+
+#[test]
+fn transfer_claim_failed_with_bad_origin() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(3), claim.clone(), None, None));
+
+		// 只有 TransferOrigin 允许的账户可以直接转移存证
+		assert_noop!(PoeModule::transfer_claim(RuntimeOrigin::signed(3), claim.clone(), 1), BadOrigin);
+	});
+}
+
+#[test]
+fn offer_and_accept_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		let deposit = CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE;
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+		assert_noop!(
+			PoeModule::offer_claim(RuntimeOrigin::signed(2), claim.clone(), 3),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+		System::assert_last_event(Event::ClaimOffered(1, 2, claim.clone(), 1 + OFFER_TIMEOUT).into());
+
+		// 转移请求只能由接收方确认
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(3), claim.clone()),
+			Error::<Test>::NotOfferRecipient
+		);
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()));
+		System::assert_last_event(Event::ClaimTransfered(1, 2, claim.clone()).into());
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+		assert!(!PendingOffers::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), deposit);
+
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+	});
+}
+
+#[test]
+fn accept_claim_failed_when_offer_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+
+		System::set_block_number(1 + OFFER_TIMEOUT);
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::OfferExpired
+		);
+	});
+}
+
+#[test]
+fn cancel_offer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+		assert_noop!(
+			PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+		assert_noop!(
+			PoeModule::cancel_offer(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::cancel_offer(RuntimeOrigin::signed(1), claim.clone()));
+		System::assert_last_event(Event::ClaimOfferCancelled(1, claim.clone()).into());
+		assert_noop!(
+			PoeModule::accept_claim(RuntimeOrigin::signed(2), claim.clone()),
+			Error::<Test>::OfferNotExist
+		);
+
+		// 吊销存证会同时清除未完成的转移请求
+		assert_ok!(PoeModule::offer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert!(!PendingOffers::<Test>::contains_key(&claim));
 	});
 }
 
@@ -88,7 +243,7 @@ fn transfer_claim_failed_when_claim_is_not_exist() {
 fn transfer_claim_failed_with_wrong_owner() {
 	new_test_ext().execute_with(|| {
 		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
-		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone()));
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
 
 		assert_noop!(
 			PoeModule::transfer_claim(RuntimeOrigin::signed(2), claim.clone(), 3),
@@ -96,3 +251,661 @@ fn transfer_claim_failed_with_wrong_owner() {
 		);
 	});
 }
+
+#[test]
+fn create_claim_failed_with_past_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+
+		assert_noop!(
+			PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn expired_claim_is_pruned_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None));
+		assert!(ExpiringClaims::<Test>::contains_key(5, &claim));
+
+		// 过期之前不会被清理
+		PoeModule::on_idle(4, Weight::MAX);
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		// 剩余权重不足时不会被清理
+		System::set_block_number(5);
+		assert_eq!(PoeModule::on_idle(5, Weight::zero()), Weight::zero());
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		// 过期后被清理，押金退还
+		PoeModule::on_idle(5, Weight::MAX);
+		assert_eq!(Proofs::<Test>::get(&claim), None);
+		assert!(!ExpiringClaims::<Test>::contains_key(5, &claim));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+		System::assert_last_event(Event::ClaimExpired(1, claim).into());
+	});
+}
+
+#[test]
+fn expired_claim_cannot_be_revoked_but_can_be_recreated() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None));
+
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ClaimExpired
+		);
+
+		// 尚未被清理的过期存证可以被其他人重新创建
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claim.clone(), None, None));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(2));
+		assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn extend_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5), None));
+
+		assert_noop!(
+			PoeModule::extend_claim(RuntimeOrigin::signed(2), claim.clone(), Some(10)),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::extend_claim(RuntimeOrigin::signed(1), claim.clone(), Some(5)),
+			Error::<Test>::InvalidExpiry
+		);
+
+		assert_ok!(PoeModule::extend_claim(RuntimeOrigin::signed(1), claim.clone(), Some(10)));
+		assert!(!ExpiringClaims::<Test>::contains_key(5, &claim));
+		assert!(ExpiringClaims::<Test>::contains_key(10, &claim));
+		System::assert_last_event(Event::ClaimExtended(1, claim.clone(), Some(10)).into());
+
+		// 旧的过期区块到达时不会清理
+		PoeModule::on_idle(5, Weight::MAX);
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		// 设为永久有效
+		assert_ok!(PoeModule::extend_claim(RuntimeOrigin::signed(1), claim.clone(), None));
+		assert!(!ExpiringClaims::<Test>::contains_key(10, &claim));
+		assert_eq!(Proofs::<Test>::get(&claim).and_then(|info| info.expires_at), None);
+	});
+}
+
+fn batch(seeds: &[u8]) -> BoundedVec<BoundedVec<u8, ConstU32<32>>, ConstU32<10>> {
+	seeds
+		.iter()
+		.map(|seed| BoundedVec::try_from(vec![*seed; 32]).unwrap())
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn create_claims_works() {
+	new_test_ext().execute_with(|| {
+		let claims = batch(&[1, 2, 3]);
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(1),
+			claims.clone(),
+			None,
+			BatchMode::AllOrNothing
+		));
+
+		for claim in claims.iter() {
+			assert_eq!(Proofs::<Test>::get(claim).map(|info| info.owner), Some(1));
+		}
+		assert_eq!(Balances::reserved_balance(1), 3 * (CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE));
+	});
+}
+
+#[test]
+fn create_claims_all_or_nothing_fails_when_any_claim_exists() {
+	new_test_ext().execute_with(|| {
+		let claims = batch(&[1, 2, 3]);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claims[1].clone(), None, None));
+
+		assert_noop!(
+			PoeModule::create_claims(RuntimeOrigin::signed(1), claims.clone(), None, BatchMode::AllOrNothing),
+			Error::<Test>::ProofAlreadyExist
+		);
+
+		// 批次内重复的存证同样失败
+		assert_noop!(
+			PoeModule::create_claims(RuntimeOrigin::signed(1), batch(&[4, 4]), None, BatchMode::AllOrNothing),
+			Error::<Test>::ProofAlreadyExist
+		);
+	});
+}
+
+#[test]
+fn create_claims_best_effort_reports_existing_claims() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claims = batch(&[1, 2, 3, 1]);
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(2), claims[1].clone(), None, None));
+
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(1),
+			claims.clone(),
+			None,
+			BatchMode::BestEffort
+		));
+
+		assert_eq!(Proofs::<Test>::get(&claims[0]).map(|info| info.owner), Some(1));
+		assert_eq!(Proofs::<Test>::get(&claims[1]).map(|info| info.owner), Some(2));
+		assert_eq!(Proofs::<Test>::get(&claims[2]).map(|info| info.owner), Some(1));
+		assert_eq!(Balances::reserved_balance(1), 2 * (CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE));
+
+		let skipped = vec![claims[1].clone(), claims[3].clone()].try_into().unwrap();
+		System::assert_last_event(Event::ClaimsSkipped(1, skipped).into());
+	});
+}
+
+/// 由四个文档哈希构造 Merkle 树，返回叶子和根
+fn merkle_tree() -> (Vec<H256>, H256) {
+	let leaves: Vec<H256> = (0u8..4).map(|i| BlakeTwo256::hash(&[i])).collect();
+	let nodes: Vec<H256> = leaves.iter().map(merkle::leaf_hash::<BlakeTwo256>).collect();
+	let left = merkle::node_hash::<BlakeTwo256>(&nodes[0], &nodes[1]);
+	let right = merkle::node_hash::<BlakeTwo256>(&nodes[2], &nodes[3]);
+	(leaves, merkle::node_hash::<BlakeTwo256>(&left, &right))
+}
+
+fn inclusion_proof(index: usize) -> Vec<H256> {
+	let (leaves, _) = merkle_tree();
+	let nodes: Vec<H256> = leaves.iter().map(merkle::leaf_hash::<BlakeTwo256>).collect();
+	let sibling = nodes[index ^ 1];
+	let other_pair = if index < 2 { (&nodes[2], &nodes[3]) } else { (&nodes[0], &nodes[1]) };
+	vec![sibling, merkle::node_hash::<BlakeTwo256>(other_pair.0, other_pair.1)]
+}
+
+#[test]
+fn create_root_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, root) = merkle_tree();
+		assert_ok!(PoeModule::create_root_claim(RuntimeOrigin::signed(1), root, None));
+
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(root.as_bytes().to_vec()).unwrap();
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+		System::assert_last_event(Event::RootClaimCreated(1, root).into());
+
+		assert_noop!(
+			PoeModule::create_root_claim(RuntimeOrigin::signed(2), root, None),
+			Error::<Test>::ProofAlreadyExist
+		);
+	});
+}
+
+#[test]
+fn verify_inclusion_accepts_valid_proofs() {
+	new_test_ext().execute_with(|| {
+		let (leaves, root) = merkle_tree();
+		assert_ok!(PoeModule::create_root_claim(RuntimeOrigin::signed(1), root, None));
+
+		for (index, leaf) in leaves.iter().enumerate() {
+			assert!(PoeModule::verify_inclusion(root, *leaf, inclusion_proof(index)));
+		}
+	});
+}
+
+#[test]
+fn verify_inclusion_rejects_tampered_proofs() {
+	new_test_ext().execute_with(|| {
+		let (leaves, root) = merkle_tree();
+		assert_ok!(PoeModule::create_root_claim(RuntimeOrigin::signed(1), root, None));
+
+		// 篡改叶子
+		let forged_leaf = BlakeTwo256::hash(b"forged");
+		assert!(!PoeModule::verify_inclusion(root, forged_leaf, inclusion_proof(0)));
+
+		// 篡改证明中的节点
+		let mut proof = inclusion_proof(0);
+		proof[1] = BlakeTwo256::hash(b"tampered");
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], proof));
+
+		// 截断证明
+		let mut proof = inclusion_proof(0);
+		proof.pop();
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], proof));
+
+		// 使用其他叶子的证明
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], inclusion_proof(2)));
+
+		// 内部节点不能作为叶子
+		let nodes: Vec<H256> = leaves.iter().map(merkle::leaf_hash::<BlakeTwo256>).collect();
+		let inner = merkle::node_hash::<BlakeTwo256>(&nodes[0], &nodes[1]);
+		assert!(!PoeModule::verify_inclusion(root, inner, vec![inclusion_proof(0)[1]]));
+	});
+}
+
+#[test]
+fn verify_inclusion_requires_anchored_root() {
+	new_test_ext().execute_with(|| {
+		let (leaves, root) = merkle_tree();
+
+		// 根尚未存证
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], inclusion_proof(0)));
+
+		// 根过期后不再有效
+		assert_ok!(PoeModule::create_root_claim(RuntimeOrigin::signed(1), root, Some(5)));
+		assert!(PoeModule::verify_inclusion(root, leaves[0], inclusion_proof(0)));
+		System::set_block_number(5);
+		assert!(!PoeModule::verify_inclusion(root, leaves[0], inclusion_proof(0)));
+	});
+}
+
+#[test]
+fn claims_by_owner_follows_ownership() {
+	new_test_ext().execute_with(|| {
+		let claim = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		let other = BoundedVec::try_from(vec![1u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(1),
+			vec![other.clone()].try_into().unwrap(),
+			Some(5),
+			BatchMode::AllOrNothing
+		));
+
+		let mut claims = PoeModule::claims_of(1);
+		claims.sort();
+		assert_eq!(claims, vec![claim.clone(), other.clone()]);
+
+		// 转移后索引跟随新的所有者
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+		assert_eq!(PoeModule::claims_of(1), vec![other.clone()]);
+		assert_eq!(PoeModule::claims_of(2), vec![claim.clone()]);
+
+		// 吊销后从索引中删除
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(2), claim.clone()));
+		assert!(PoeModule::claims_of(2).is_empty());
+
+		// 过期清理后从索引中删除
+		PoeModule::on_idle(5, Weight::MAX);
+		assert!(PoeModule::claims_of(1).is_empty());
+		assert!(!ClaimsByOwner::<Test>::contains_key(1, &other));
+	});
+}
+
+fn metadata(
+	mime_type: &str,
+	description: &str,
+) -> ClaimMetadata<BoundedVec<u8, ConstU32<32>>, BoundedVec<u8, ConstU32<64>>> {
+	ClaimMetadata {
+		mime_type: mime_type.as_bytes().to_vec().try_into().unwrap(),
+		hash_algorithm: HashAlgorithm::Sha2_256,
+		description: description.as_bytes().to_vec().try_into().unwrap(),
+	}
+}
+
+#[test]
+fn create_claim_with_metadata_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		let meta = metadata("application/pdf", "contract");
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, Some(meta.clone())));
+
+		System::assert_last_event(Event::ClaimCreated(1, claim.clone(), Some(meta.clone())).into());
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().metadata, Some(meta.clone()));
+
+		// 元数据同样按字节收取押金
+		let deposit = CLAIM_DEPOSIT_BASE + (32 + meta.encoded_size() as u128) * CLAIM_DEPOSIT_PER_BYTE;
+		assert_eq!(Balances::reserved_balance(1), deposit);
+	});
+}
+
+#[test]
+fn update_metadata_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_claim(RuntimeOrigin::signed(1), claim.clone(), None, None));
+
+		let meta = metadata("image/png", "a photo of the whiteboard");
+		assert_noop!(
+			PoeModule::update_metadata(RuntimeOrigin::signed(2), claim.clone(), Some(meta.clone())),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::update_metadata(RuntimeOrigin::signed(1), claim.clone(), Some(meta.clone())));
+		System::assert_last_event(Event::ClaimMetadataUpdated(1, claim.clone(), Some(meta.clone())).into());
+		let info = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!(info.metadata, Some(meta.clone()));
+		let deposit = CLAIM_DEPOSIT_BASE + (32 + meta.encoded_size() as u128) * CLAIM_DEPOSIT_PER_BYTE;
+		assert_eq!((info.deposit, Balances::reserved_balance(1)), (deposit, deposit));
+
+		// 元数据随存证一起转移
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 2));
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().metadata, Some(meta));
+		assert_eq!(Balances::reserved_balance(2), deposit);
+
+		// 清除元数据会退还多出的押金
+		assert_ok!(PoeModule::update_metadata(RuntimeOrigin::signed(2), claim.clone(), None));
+		assert_eq!(Proofs::<Test>::get(&claim).unwrap().metadata, None);
+		assert_eq!(Balances::reserved_balance(2), CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE);
+	});
+}
+
+#[test]
+fn update_metadata_failed_for_joint_claim() {
+	new_test_ext().execute_with(|| {
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_joint_claim(
+			RuntimeOrigin::signed(1),
+			claim.clone(),
+			owners(&[2]),
+			2,
+			None
+		));
+
+		assert_noop!(
+			PoeModule::update_metadata(RuntimeOrigin::signed(1), claim, Some(metadata("text/plain", ""))),
+			Error::<Test>::ApprovalRequired
+		);
+	});
+}
+
+fn owners(accounts: &[u64]) -> BoundedVec<u64, ConstU32<3>> {
+	accounts.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn create_joint_claim_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+
+		assert_noop!(
+			PoeModule::create_joint_claim(RuntimeOrigin::signed(1), claim.clone(), owners(&[2, 3]), 4, None),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			PoeModule::create_joint_claim(RuntimeOrigin::signed(1), claim.clone(), owners(&[2, 3]), 0, None),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			PoeModule::create_joint_claim(RuntimeOrigin::signed(1), claim.clone(), owners(&[2, 3, 4]), 2, None),
+			Error::<Test>::TooManyOwners
+		);
+		assert_noop!(
+			PoeModule::create_joint_claim(RuntimeOrigin::signed(1), claim.clone(), owners(&[2, 1]), 2, None),
+			Error::<Test>::DuplicateOwner
+		);
+
+		assert_ok!(PoeModule::create_joint_claim(
+			RuntimeOrigin::signed(1),
+			claim.clone(),
+			owners(&[2, 3]),
+			2,
+			None
+		));
+		System::assert_last_event(Event::JointClaimCreated(1, claim.clone(), owners(&[2, 3]), 2).into());
+
+		// 所有共有者都能查到该存证，押金由创建者支付
+		assert_eq!(PoeModule::claims_of(2), vec![claim.clone()]);
+		assert_eq!(PoeModule::claims_of(3), vec![claim.clone()]);
+		assert_eq!(Balances::reserved_balance(1), CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// 共有存证不能被单个所有者直接吊销或转移
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(1), claim.clone()),
+			Error::<Test>::ApprovalRequired
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(1), claim.clone(), 4),
+			Error::<Test>::ApprovalRequired
+		);
+	});
+}
+
+#[test]
+fn approve_claim_action_executes_at_threshold() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		let deposit = CLAIM_DEPOSIT_BASE + 32 * CLAIM_DEPOSIT_PER_BYTE;
+		assert_ok!(PoeModule::create_joint_claim(
+			RuntimeOrigin::signed(1),
+			claim.clone(),
+			owners(&[2, 3]),
+			2,
+			None
+		));
+		let _ = Balances::deposit_creating(&4, INITIAL_BALANCE);
+		let transfer = ClaimAction::Transfer(4);
+
+		assert_noop!(
+			PoeModule::approve_claim_action(RuntimeOrigin::signed(4), claim.clone(), transfer.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::approve_claim_action(RuntimeOrigin::signed(2), claim.clone(), transfer.clone()));
+		System::assert_last_event(Event::ClaimActionApproved(2, claim.clone(), transfer.clone(), 1).into());
+		assert!(PendingActions::<Test>::contains_key(&claim));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+
+		assert_noop!(
+			PoeModule::approve_claim_action(RuntimeOrigin::signed(2), claim.clone(), transfer.clone()),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			PoeModule::approve_claim_action(RuntimeOrigin::signed(3), claim.clone(), ClaimAction::Revoke),
+			Error::<Test>::ConflictingAction
+		);
+
+		// 第二个批准达到阈值，向接收方发起转移请求
+		assert_ok!(PoeModule::approve_claim_action(RuntimeOrigin::signed(3), claim.clone(), transfer.clone()));
+		System::assert_last_event(Event::ClaimOffered(1, 4, claim.clone(), 1 + OFFER_TIMEOUT).into());
+		assert!(!PendingActions::<Test>::contains_key(&claim));
+		assert_eq!(Proofs::<Test>::get(&claim).map(|info| info.owner), Some(1));
+
+		// 接收方确认后存证转移给新的唯一所有者
+		assert_ok!(PoeModule::accept_claim(RuntimeOrigin::signed(4), claim.clone()));
+		System::assert_last_event(Event::ClaimTransfered(1, 4, claim.clone()).into());
+
+		let info = Proofs::<Test>::get(&claim).unwrap();
+		assert_eq!((info.owner, info.threshold, info.co_owners.is_empty()), (4, 1, true));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(4), deposit);
+		assert!(PoeModule::claims_of(1).is_empty());
+		assert!(PoeModule::claims_of(2).is_empty());
+		assert!(PoeModule::claims_of(3).is_empty());
+		assert_eq!(PoeModule::claims_of(4), vec![claim]);
+	});
+}
+
+#[test]
+fn extend_joint_claim_requires_approval() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_joint_claim(
+			RuntimeOrigin::signed(1),
+			claim.clone(),
+			owners(&[2, 3]),
+			2,
+			Some(5)
+		));
+
+		// 主所有者不能单独修改共有存证的有效期
+		assert_noop!(
+			PoeModule::extend_claim(RuntimeOrigin::signed(1), claim.clone(), None),
+			Error::<Test>::ApprovalRequired
+		);
+		assert_noop!(
+			PoeModule::approve_claim_action(RuntimeOrigin::signed(2), claim.clone(), ClaimAction::Extend(Some(5))),
+			Error::<Test>::InvalidExpiry
+		);
+
+		let extend = ClaimAction::Extend(Some(10));
+		assert_ok!(PoeModule::approve_claim_action(RuntimeOrigin::signed(2), claim.clone(), extend.clone()));
+		assert_eq!(Proofs::<Test>::get(&claim).and_then(|info| info.expires_at), Some(5));
+
+		// 第二个批准达到阈值，有效期被延长
+		assert_ok!(PoeModule::approve_claim_action(RuntimeOrigin::signed(1), claim.clone(), extend));
+		System::assert_last_event(Event::ClaimExtended(1, claim.clone(), Some(10)).into());
+		assert!(!PendingActions::<Test>::contains_key(&claim));
+		assert!(!ExpiringClaims::<Test>::contains_key(5, &claim));
+		assert!(ExpiringClaims::<Test>::contains_key(10, &claim));
+		assert_eq!(Proofs::<Test>::get(&claim).and_then(|info| info.expires_at), Some(10));
+	});
+}
+
+#[test]
+fn pending_claim_action_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![0u8; 32]).unwrap();
+		assert_ok!(PoeModule::create_joint_claim(
+			RuntimeOrigin::signed(1),
+			claim.clone(),
+			owners(&[2, 3]),
+			2,
+			None
+		));
+
+		assert_ok!(PoeModule::approve_claim_action(
+			RuntimeOrigin::signed(2),
+			claim.clone(),
+			ClaimAction::Transfer(4)
+		));
+
+		// 超时后旧的批准被丢弃，可以发起新的操作
+		System::set_block_number(1 + ACTION_TIMEOUT);
+		assert_ok!(PoeModule::approve_claim_action(RuntimeOrigin::signed(3), claim.clone(), ClaimAction::Revoke));
+		System::assert_has_event(Event::ClaimActionExpired(claim.clone(), ClaimAction::Transfer(4)).into());
+		assert!(Proofs::<Test>::contains_key(&claim));
+
+		assert_ok!(PoeModule::approve_claim_action(RuntimeOrigin::signed(1), claim.clone(), ClaimAction::Revoke));
+		System::assert_last_event(Event::ClaimRevoked(1, claim.clone()).into());
+		assert!(!Proofs::<Test>::contains_key(&claim));
+		assert!(!PendingActions::<Test>::contains_key(&claim));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(PoeModule::claims_of(2).is_empty());
+	});
+}
+
+#[storage_alias]
+type OldProofs = StorageMap<PoeModule, Blake2_128Concat, BoundedVec<u8, ConstU32<32>>, (u64, u64)>;
+
+#[storage_alias]
+type OldClaimDeposits = StorageMap<PoeModule, Blake2_128Concat, BoundedVec<u8, ConstU32<32>>, u128>;
+
+#[test]
+fn migrate_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![1u8; 32]).unwrap();
+		let free_claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![2u8; 8]).unwrap();
+		OldProofs::insert(&claim, (1, 3));
+		OldClaimDeposits::insert(&claim, 42);
+		OldProofs::insert(&free_claim, (2, 4));
+
+		crate::migrations::v1::migrate::<Test>();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			v1::Proofs::<Test>::get(&claim),
+			Some(v1::ClaimInfo { owner: 1, registered_at: 3, expires_at: None, deposit: 42 })
+		);
+		assert_eq!(
+			v1::Proofs::<Test>::get(&free_claim),
+			Some(v1::ClaimInfo { owner: 2, registered_at: 4, expires_at: None, deposit: 0 })
+		);
+		assert!(!OldClaimDeposits::contains_key(&claim));
+	});
+}
+
+#[test]
+fn migrate_to_v2_builds_owner_index() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<PoeModule>();
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![1u8; 32]).unwrap();
+		v1::Proofs::<Test>::insert(
+			&claim,
+			v1::ClaimInfo { owner: 3, registered_at: 1, expires_at: None, deposit: 0 },
+		);
+		assert!(PoeModule::claims_of(3).is_empty());
+
+		crate::migrations::v2::migrate::<Test>();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert_eq!(PoeModule::claims_of(3), vec![claim]);
+	});
+}
+
+#[test]
+fn migrate_to_v3_makes_claims_single_owned() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<PoeModule>();
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![1u8; 32]).unwrap();
+		v1::Proofs::<Test>::insert(
+			&claim,
+			v1::ClaimInfo { owner: 3, registered_at: 1, expires_at: Some(9), deposit: 7 },
+		);
+
+		crate::migrations::v3::migrate::<Test>();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 3);
+		assert_eq!(
+			v3::Proofs::<Test>::get(&claim),
+			Some(v3::ClaimInfo {
+				owner: 3,
+				registered_at: 1,
+				expires_at: Some(9),
+				deposit: 7,
+				co_owners: Default::default(),
+				threshold: 1,
+			})
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v4_adds_empty_metadata() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<PoeModule>();
+		let claim: BoundedVec<u8, ConstU32<32>> = BoundedVec::try_from(vec![1u8; 32]).unwrap();
+		v3::Proofs::<Test>::insert(
+			&claim,
+			v3::ClaimInfo {
+				owner: 1,
+				registered_at: 1,
+				expires_at: None,
+				deposit: 7,
+				co_owners: owners(&[2]),
+				threshold: 2,
+			},
+		);
+
+		crate::migrations::v4::migrate::<Test>();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 4);
+		assert_eq!(
+			Proofs::<Test>::get(&claim),
+			Some(ClaimInfo {
+				owner: 1,
+				registered_at: 1,
+				expires_at: None,
+				deposit: 7,
+				co_owners: owners(&[2]),
+				threshold: 2,
+				metadata: None,
+			})
+		);
+	});
+}
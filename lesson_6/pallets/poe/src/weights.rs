@@ -1,34 +1,13 @@
-
-//! Autogenerated weights for pallet_poe
+//! Placeholder weights for pallet_poe
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-07-01, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `baru-pc`, CPU: `AMD Ryzen 7 5800H with Radeon Graphics`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --execution
-// wasm
-// --wasm-execution
-// compiled
-// --pallet
-// pallet_poe
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// ./pallets/poe/src/weights.rs
-// --template
-// .maintain/frame-weight-template.hbs
+//! These are NOT benchmark results: the benchmark CLI was not run when they were written. The
+//! base execution times are rounded guesses, while the database reads, writes and proof sizes
+//! follow the storage items each call accesses. Regenerate this file from `benchmarking.rs` on
+//! reference hardware before relying on it:
+//!
+//! ./target/release/node-template benchmark pallet --chain dev --execution wasm \
+//!     --wasm-execution compiled --pallet pallet_poe --extrinsic '*' --steps 20 --repeat 10 \
+//!     --output ./pallets/poe/src/weights.rs --template .maintain/frame-weight-template.hbs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -42,97 +21,391 @@ pub trait WeightInfo {
 	fn create_claim(d: u32, ) -> Weight;
 	fn revoke_claim(d: u32, ) -> Weight;
 	fn transfer_claim(d: u32, ) -> Weight;
+	fn extend_claim(d: u32, ) -> Weight;
+	fn prune_expired_claim() -> Weight;
+	fn create_claims(b: u32, ) -> Weight;
+	fn create_root_claim() -> Weight;
+	fn create_joint_claim(o: u32, ) -> Weight;
+	fn approve_claim_action(o: u32, ) -> Weight;
+	fn update_metadata(d: u32, m: u32, ) -> Weight;
+	fn offer_claim(d: u32, ) -> Weight;
+	fn accept_claim(d: u32, ) -> Weight;
+	fn cancel_offer(d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_poe using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: PoeModule Proofs (r:1 w:1)
-	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	/// The range of component `d` is `[0, 32]`.
 	fn create_claim(d: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `3550`
-		// Minimum execution time: 18_435_000 picoseconds.
-		Weight::from_parts(20_712_998, 3550)
-			// Standard Error: 22_570
-			.saturating_add(Weight::from_parts(48_053, 0).saturating_mul(d.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: PoeModule Proofs (r:1 w:1)
-	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
 	/// The range of component `d` is `[0, 32]`.
 	fn revoke_claim(d: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `85 + d * (1 ±0)`
-		//  Estimated: `3550`
-		// Minimum execution time: 19_497_000 picoseconds.
-		Weight::from_parts(22_044_258, 3550)
-			// Standard Error: 38_545
-			.saturating_add(Weight::from_parts(49_028, 0).saturating_mul(d.into()))
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn transfer_claim(d: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 8629)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:2)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn extend_claim(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3423)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn prune_expired_claim() -> Weight {
+		Weight::from_parts(35_000_000, 6026)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule Proofs (r:256 w:256)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:256)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:256)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 256]`.
+	fn create_claims(b: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 2603)
+			.saturating_add(Weight::from_parts(30_000_000, 3423).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn create_root_claim() -> Weight {
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: PoeModule Proofs (r:1 w:1)
-	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:16)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 15]`.
+	fn create_joint_claim(o: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingActions (r:1 w:1)
+	/// Proof: PoeModule PendingActions (max_values: None, max_size: Some(599), added: 3074, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 15]`.
+	fn approve_claim_action(o: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 6497)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// The range of component `d` is `[0, 32]`.
-	fn transfer_claim(d: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `85 + d * (1 ±0)`
-		//  Estimated: `3550`
-		// Minimum execution time: 21_080_000 picoseconds.
-		Weight::from_parts(24_254_874, 3550)
-			// Standard Error: 34_990
-			.saturating_add(Weight::from_parts(36_829, 0).saturating_mul(d.into()))
+	/// The range of component `m` is `[0, 320]`.
+	fn update_metadata(d: u32, m: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 6026)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn offer_claim(d: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3423)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: PoeModule PendingOffers (r:1 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingActions (r:0 w:1)
+	/// Proof: PoeModule PendingActions (max_values: None, max_size: Some(599), added: 3074, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn accept_claim(d: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 11189)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:1 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn cancel_offer(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 5983)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: PoeModule Proofs (r:1 w:1)
-	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	/// The range of component `d` is `[0, 32]`.
 	fn create_claim(d: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `3550`
-		// Minimum execution time: 18_435_000 picoseconds.
-		Weight::from_parts(20_712_998, 3550)
-			// Standard Error: 22_570
-			.saturating_add(Weight::from_parts(48_053, 0).saturating_mul(d.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: PoeModule Proofs (r:1 w:1)
-	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
 	/// The range of component `d` is `[0, 32]`.
 	fn revoke_claim(d: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `85 + d * (1 ±0)`
-		//  Estimated: `3550`
-		// Minimum execution time: 19_497_000 picoseconds.
-		Weight::from_parts(22_044_258, 3550)
-			// Standard Error: 38_545
-			.saturating_add(Weight::from_parts(49_028, 0).saturating_mul(d.into()))
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn transfer_claim(d: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 8629)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:2)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn extend_claim(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3423)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn prune_expired_claim() -> Weight {
+		Weight::from_parts(35_000_000, 6026)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: PoeModule Proofs (r:256 w:256)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:256)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:256)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 256]`.
+	fn create_claims(b: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 2603)
+			.saturating_add(Weight::from_parts(30_000_000, 3423).saturating_mul(b.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
 	}
 	/// Storage: PoeModule Proofs (r:1 w:1)
-	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:1)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn create_root_claim() -> Weight {
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ExpiringClaims (r:0 w:1)
+	/// Proof: PoeModule ExpiringClaims (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:16)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 15]`.
+	fn create_joint_claim(o: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 6026)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(o.into())))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingActions (r:1 w:1)
+	/// Proof: PoeModule PendingActions (max_values: None, max_size: Some(599), added: 3074, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 15]`.
+	fn approve_claim_action(o: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 6497)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// The range of component `d` is `[0, 32]`.
-	fn transfer_claim(d: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `85 + d * (1 ±0)`
-		//  Estimated: `3550`
-		// Minimum execution time: 21_080_000 picoseconds.
-		Weight::from_parts(24_254_874, 3550)
-			// Standard Error: 34_990
-			.saturating_add(Weight::from_parts(36_829, 0).saturating_mul(d.into()))
+	/// The range of component `m` is `[0, 320]`.
+	fn update_metadata(d: u32, m: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 6026)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:0 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn offer_claim(d: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3423)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: PoeModule PendingOffers (r:1 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: PoeModule Proofs (r:1 w:1)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: PoeModule ClaimsByOwner (r:0 w:2)
+	/// Proof: PoeModule ClaimsByOwner (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingActions (r:0 w:1)
+	/// Proof: PoeModule PendingActions (max_values: None, max_size: Some(599), added: 3074, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn accept_claim(d: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 11189)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: PoeModule Proofs (r:1 w:0)
+	/// Proof: PoeModule Proofs (max_values: None, max_size: Some(948), added: 3423, mode: MaxEncodedLen)
+	/// Storage: PoeModule PendingOffers (r:1 w:1)
+	/// Proof: PoeModule PendingOffers (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 32]`.
+	fn cancel_offer(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 5983)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,15 @@
+//! Runtime API definition for the courier price pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PriceApi {
+		/// The median price of the courier `kuaidi_com`, in cents.
+		fn median_price(kuaidi_com: Vec<u8>) -> Option<u32>;
+
+		/// The median prices of all couriers that have one, in cents.
+		fn median_prices() -> Vec<(Vec<u8>, u32)>;
+	}
+}
@@ -5,7 +5,14 @@
 /// <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 mod price_struct;
+pub use price_struct::{DeliverPrice, DeliverPrices, KuaidiCom};
 
 use sp_core::crypto::KeyTypeId;
 
@@ -54,20 +61,24 @@ pub mod pallet {
 	use frame_support::inherent::Vec;
 	use sp_runtime::{
 		offchain::{http, Duration},
+		traits::IdentifyAccount,
 		transaction_validity::{InvalidTransaction, TransactionValidity, ValidTransaction},
 		RuntimeDebug,
 	};
 
-	use crate::price_struct::{DeliverPrice, DeliverPriceResponse};
+	use crate::price_struct::{DeliverPriceResponse, DeliverPrices, KuaidiCom, MAX_PRICES};
 	const ONCHAIN_TX_KEY: &[u8] = b"price::indexing_parcel_weight";
 
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
-	pub struct Payload<Public> {
-		price_data: BoundedVec<DeliverPrice, ConstU32<10>>,
-		public: Public,
+	pub struct Payload<Public, BlockNumber> {
+		pub price_data: DeliverPrices,
+		/// The block the prices were fetched at. Every authority has to submit for increasing
+		/// blocks no older than `MaxPriceAge`, so a payload can't be replayed.
+		pub block_number: BlockNumber,
+		pub public: Public,
 	}
 
-	impl<T: SigningTypes> SignedPayload<T> for Payload<T::Public> {
+	impl<T: SigningTypes> SignedPayload<T> for Payload<T::Public, T::BlockNumber> {
 		fn public(&self) -> T::Public {
 			self.public.clone()
 		}
@@ -88,6 +99,79 @@ pub mod pallet {
 		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin that can add and remove price authorities.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The maximum number of price authorities.
+		#[pallet::constant]
+		type MaxAuthorities: Get<u32>;
+		/// The number of most recent prices of a courier its median price is taken over. Only the
+		/// prices of the latest submission of every authority are kept, so it should be at least
+		/// `MaxAuthorities`.
+		#[pallet::constant]
+		type WindowLength: Get<u32>;
+		/// The most blocks a submitted payload may be behind the current block.
+		#[pallet::constant]
+		type MaxPriceAge: Get<Self::BlockNumber>;
+	}
+
+	/// 可以提交价格的账户，提交的签名载荷的公钥必须对应其中一个账户
+	#[pallet::storage]
+	#[pallet::getter(fn authorities)]
+	pub type Authorities<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxAuthorities>, ValueQuery>;
+
+	/// 每个授权账户最近一次提交的价格和价格对应的区块
+	#[pallet::storage]
+	#[pallet::getter(fn submitted_prices)]
+	pub type SubmittedPrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::BlockNumber, DeliverPrices)>;
+
+	/// 每个账户最近一次提交价格的区块，移除授权后仍然保留，重新授权后也不能重放旧的载荷
+	#[pallet::storage]
+	#[pallet::getter(fn last_submission)]
+	pub type LastSubmission<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// 每个快递公司最近提交的 `WindowLength` 个价格和提交的账户，按提交的先后排列。
+	/// 每个账户只保留最近一次提交中的价格，一个账户重复提交不能占满窗口
+	#[pallet::storage]
+	#[pallet::getter(fn price_window)]
+	pub type PriceWindows<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		KuaidiCom,
+		BoundedVec<(T::AccountId, u32), T::WindowLength>,
+		ValueQuery,
+	>;
+
+	/// 上一个区块中有新价格的快递公司，在 on_initialize 中重新计算中位数
+	#[pallet::storage]
+	pub type UpdatedCouriers<T> = StorageMap<_, Blake2_128Concat, KuaidiCom, ()>;
+
+	/// 每个快递公司价格窗口的中位数，单位是分
+	#[pallet::storage]
+	#[pallet::getter(fn median_price)]
+	pub type MedianPrices<T> = StorageMap<_, Blake2_128Concat, KuaidiCom, u32>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub authorities: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { authorities: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let authorities = BoundedVec::<_, T::MaxAuthorities>::try_from(self.authorities.clone())
+				.expect("Too many price authorities in the genesis config");
+			Authorities::<T>::put(authorities);
+		}
 	}
 
 	// Pallets use events to inform users when important changes are made.
@@ -98,6 +182,17 @@ pub mod pallet {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		ParcelWeightStored { parcel_weight: BoundedVec<u8, ConstU32<4>>, who: T::AccountId },
+		PricesSubmitted { who: T::AccountId, block_number: T::BlockNumber },
+		AuthorityAdded { who: T::AccountId },
+		AuthorityRemoved { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NotAuthority,
+		AlreadyAuthority,
+		TooManyAuthorities,
+		StalePrices,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -106,18 +201,32 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(0)]
+		#[pallet::weight(Pallet::<T>::submission_weight(payload.price_data.len() as u64))]
 		pub fn unsigned_extrinsic_with_signed_payload(
 			origin: OriginFor<T>,
-			payload: Payload<T::Public>,
+			payload: Payload<T::Public, T::BlockNumber>,
 			_signature: T::Signature,
 		) -> DispatchResult {
 			ensure_none(origin)?;
 
+			// 签名已经在 validate_unsigned 中检查过
+			let who = payload.public.clone().into_account();
+			Self::check_submission(&who, payload.block_number)?;
+
 			log::info!(
 				"OCW ==> in call unsigned_extrinsic_with_signed_payload: {:?}",
 				payload.price_data
 			);
+			// 上次提交中的价格全部换成这次提交的价格
+			Self::remove_prices(&who);
+			for price in payload.price_data.iter() {
+				Self::record_price(&who, &price.kuaidi_com, price.total_price);
+			}
+			let block_number = payload.block_number;
+			SubmittedPrices::<T>::insert(&who, (block_number, payload.price_data));
+			LastSubmission::<T>::insert(&who, block_number);
+
+			Self::deposit_event(Event::PricesSubmitted { who, block_number });
 			// Return a successful DispatchResultWithPostInfo
 			Ok(())
 		}
@@ -143,6 +252,45 @@ pub mod pallet {
 			Self::deposit_event(Event::ParcelWeightStored { parcel_weight, who: _who });
 			Ok(())
 		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn add_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Authorities::<T>::try_mutate(|authorities| -> DispatchResult {
+				ensure!(!authorities.contains(&who), Error::<T>::AlreadyAuthority);
+				authorities.try_push(who.clone()).map_err(|_| Error::<T>::TooManyAuthorities)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AuthorityAdded { who });
+			Ok(())
+		}
+
+		/// 移除授权账户，它最近一次提交的价格一起从价格窗口中删除，中位数在下一个区块重新计算。
+		/// 最近一次提交的区块保留
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			// 读写授权账户列表，再删除最多 `MAX_PRICES` 个价格
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(Pallet::<T>::removal_weight())
+		})]
+		pub fn remove_authority(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Authorities::<T>::try_mutate(|authorities| -> DispatchResult {
+				let index = authorities
+					.iter()
+					.position(|authority| *authority == who)
+					.ok_or(Error::<T>::NotAuthority)?;
+				authorities.remove(index);
+				Ok(())
+			})?;
+			Self::remove_prices(&who);
+
+			Self::deposit_event(Event::AuthorityRemoved { who });
+			Ok(())
+		}
 	}
 
 	// 发送未签名交易时需要实现的 trait
@@ -171,7 +319,17 @@ pub mod pallet {
 					if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
 						return InvalidTransaction::BadProof.into()
 					}
-					valid_tx(b"unsigned_extrinsic_with_signed_payload".to_vec())
+					// 只接受授权账户提交的、比上次提交更新的价格
+					let who = payload.public.clone().into_account();
+					match Self::check_submission(&who, payload.block_number) {
+						Ok(()) => (),
+						Err(Error::<T>::NotAuthority) =>
+							return InvalidTransaction::BadSigner.into(),
+						Err(_) => return InvalidTransaction::Stale.into(),
+					}
+					// 每个授权账户在每个区块只有一笔提交
+					let prefix = b"unsigned_extrinsic_with_signed_payload";
+					valid_tx((prefix, who, payload.block_number).encode())
 				},
 				_ => InvalidTransaction::Call.into(),
 			}
@@ -180,6 +338,23 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			// 快递公司的数量不超过授权账户数乘以每次提交的价格数
+			let mut count = 0u64;
+			for (kuaidi_com, ()) in UpdatedCouriers::<T>::drain() {
+				let window = Self::price_window(&kuaidi_com);
+				let prices = window.iter().map(|(_, price)| *price).collect::<Vec<_>>();
+				match crate::median(&prices) {
+					Some(price) => MedianPrices::<T>::insert(&kuaidi_com, price),
+					None => MedianPrices::<T>::remove(&kuaidi_com),
+				}
+				count += 1;
+			}
+
+			// 每个快递公司读取价格窗口，删除更新标记并写入中位数
+			T::DbWeight::get().reads_writes(1 + count, count * 2)
+		}
+
 		fn offchain_worker(block_number: T::BlockNumber) {
 			let parcel_weight = Self::get_parcel_weight_from_storage();
 			if let Ok(info) = Self::fetch_kuaidi100_price_info(parcel_weight) {
@@ -197,6 +372,7 @@ pub mod pallet {
 					// this line is to prepare and return payload
 					|acct| Payload {
 						price_data: info.clone(),
+						block_number,
 						public: acct.public.clone(),
 					},
 					|payload, signature| Call::unsigned_extrinsic_with_signed_payload {
@@ -227,10 +403,80 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// `who` 是否可以提交 `block_number` 区块的价格
+		fn check_submission(
+			who: &T::AccountId,
+			block_number: T::BlockNumber,
+		) -> Result<(), Error<T>> {
+			ensure!(Self::authorities().contains(who), Error::<T>::NotAuthority);
+
+			// 不接受未来区块或者太旧的价格，也不接受不比上次提交更新的价格
+			let now = frame_system::Pallet::<T>::block_number();
+			let last = Self::last_submission(who);
+			ensure!(
+				block_number <= now &&
+					now - block_number <= T::MaxPriceAge::get() &&
+					last.map_or(true, |last| last < block_number),
+				Error::<T>::StalePrices
+			);
+			Ok(())
+		}
+
+		/// 用 `who` 的新价格替换它在快递公司价格窗口中的价格，窗口满了时移除最早的价格
+		fn record_price(who: &T::AccountId, kuaidi_com: &KuaidiCom, price: u32) {
+			PriceWindows::<T>::mutate(kuaidi_com, |window| {
+				window.retain(|(authority, _)| authority != who);
+				if !window.is_empty() && window.len() as u32 >= T::WindowLength::get() {
+					window.remove(0);
+				}
+				let _ = window.try_push((who.clone(), price));
+			});
+			UpdatedCouriers::<T>::insert(kuaidi_com, ());
+		}
+
+		/// 从价格窗口中删除 `who` 最近一次提交的价格，这些快递公司的中位数在下一个区块重新计算
+		fn remove_prices(who: &T::AccountId) {
+			let prices = SubmittedPrices::<T>::take(who).map(|(_, prices)| prices);
+			for price in prices.iter().flatten() {
+				PriceWindows::<T>::mutate_exists(&price.kuaidi_com, |window| {
+					if let Some(prices) = window {
+						prices.retain(|(authority, _)| authority != who);
+						if prices.is_empty() {
+							*window = None;
+						}
+					}
+				});
+				UpdatedCouriers::<T>::insert(&price.kuaidi_com, ());
+			}
+		}
+
+		/// 删除上次提交的价格的权重，一次提交最多有 `MAX_PRICES` 个价格
+		fn removal_weight() -> Weight {
+			let prices = MAX_PRICES as u64;
+			// 读写上次提交的价格，每个快递公司读写价格窗口并标记更新
+			T::DbWeight::get().reads_writes(1 + prices, 1 + prices * 2)
+		}
+
+		/// 提交 `prices` 个价格的权重
+		fn submission_weight(prices: u64) -> Weight {
+			// 读取授权账户列表和最近一次提交的区块，每个价格读写价格窗口并标记更新，
+			// 最后写入这次提交的价格和区块
+			T::DbWeight::get()
+				.reads_writes(2 + prices, 2 + prices * 2)
+				.saturating_add(Self::removal_weight())
+		}
+
+		/// 所有快递公司的价格中位数
+		pub fn median_prices() -> Vec<(Vec<u8>, u32)> {
+			MedianPrices::<T>::iter()
+				.map(|(kuaidi_com, price)| (kuaidi_com.into_inner(), price))
+				.collect()
+		}
+
 		/// 获取快递100的价格信息
 		fn fetch_kuaidi100_price_info(
 			parcel_weight: BoundedVec<u8, ConstU32<4>>,
-		) -> Result<BoundedVec<DeliverPrice, ConstU32<10>>, http::Error> {
+		) -> Result<DeliverPrices, http::Error> {
 			// prepare for send request
 			let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(8_000));
 			let url = Self::get_url(parcel_weight);
@@ -289,3 +535,19 @@ pub mod pallet {
 		}
 	}
 }
+
+/// The median of `prices`, the mean of the two middle prices if there is an even number of them.
+pub fn median(prices: &[u32]) -> Option<u32> {
+	if prices.is_empty() {
+		return None
+	}
+
+	let mut sorted = prices.to_vec();
+	sorted.sort_unstable();
+	let middle = sorted.len() / 2;
+	if sorted.len() % 2 == 0 {
+		Some(((sorted[middle - 1] as u64 + sorted[middle] as u64) / 2) as u32)
+	} else {
+		Some(sorted[middle])
+	}
+}
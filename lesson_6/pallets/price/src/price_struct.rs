@@ -1,18 +1,27 @@
 use core::fmt;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::inherent::Vec;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Deserializer};
 use sp_core::ConstU32;
 use sp_runtime::BoundedVec;
 
-#[derive(Deserialize, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+/// 快递公司的编码，例如 `yuantong`
+pub type KuaidiCom = BoundedVec<u8, ConstU32<32>>;
+
+/// 一次查询最多保留的价格数量
+pub const MAX_PRICES: u32 = 10;
+
+/// 一次查询得到的价格列表
+pub type DeliverPrices = BoundedVec<DeliverPrice, ConstU32<MAX_PRICES>>;
+
+#[derive(Deserialize, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct DeliverPrice {
 	#[serde(deserialize_with = "de_string_to_bounded_bytes")]
 	pub name: BoundedVec<u8, ConstU32<32>>,
 	#[serde(deserialize_with = "de_string_to_bounded_bytes", rename(deserialize = "kuaidiCom"))]
-	pub kuaidi_com: BoundedVec<u8, ConstU32<32>>,
+	pub kuaidi_com: KuaidiCom,
 	#[serde(deserialize_with = "de_string_to_u32", rename(deserialize = "totalprice"))]
 	pub total_price: u32,
 }
@@ -32,7 +41,7 @@ impl fmt::Debug for DeliverPrice {
 pub struct DeliverPriceResponse {
 	pub status: i32,
 	#[serde(deserialize_with = "de_vec_to_bounded_vec")]
-	pub data: BoundedVec<DeliverPrice, ConstU32<10>>,
+	pub data: DeliverPrices,
 }
 
 /// 反序列化字符串到 BoundedVec<u8, ConstU32<32>>
@@ -56,17 +65,15 @@ where
 	Ok(price)
 }
 
-/// 反序列化 Vec<DeliverPrice> 到 BoundedVec<DeliverPrice, ConstU32<10>>
-pub fn de_vec_to_bounded_vec<'de, D>(
-	de: D,
-) -> Result<BoundedVec<DeliverPrice, ConstU32<10>>, D::Error>
+/// 反序列化 Vec<DeliverPrice> 到 DeliverPrices
+pub fn de_vec_to_bounded_vec<'de, D>(de: D) -> Result<DeliverPrices, D::Error>
 where
 	D: Deserializer<'de>,
 {
 	let s: Vec<DeliverPrice> = Deserialize::deserialize(de)?;
 	let a = s
 		.into_iter()
-		.take(10)
+		.take(MAX_PRICES as usize)
 		.collect::<Vec<DeliverPrice>>()
 		.try_into()
 		.map_err(|_| serde::de::Error::custom("BoundedVec error"))?;
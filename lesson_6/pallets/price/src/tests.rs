@@ -0,0 +1,258 @@
+use crate::{
+	median, mock::*, Call, DeliverPrice, DeliverPrices, Error, Event, KuaidiCom, Payload,
+	PriceWindows, SubmittedPrices,
+};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult, traits::Hooks};
+use sp_core::Pair;
+use sp_runtime::{
+	traits::{BadOrigin, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+};
+
+fn courier(name: &[u8]) -> KuaidiCom {
+	KuaidiCom::try_from(name.to_vec()).unwrap()
+}
+
+fn prices(list: &[(&[u8], u32)]) -> DeliverPrices {
+	let list = list
+		.iter()
+		.map(|(name, total_price)| DeliverPrice {
+			name: courier(name),
+			kuaidi_com: courier(name),
+			total_price: *total_price,
+		})
+		.collect::<Vec<_>>();
+	DeliverPrices::try_from(list).unwrap()
+}
+
+// 由 `seed` 对应的密钥签名的提交价格的调用
+fn submission(seed: u8, block_number: u64, price_data: DeliverPrices) -> Call<Test> {
+	let payload = Payload { price_data, block_number, public: account(seed) };
+	let signature = pair(seed).sign(&payload.encode());
+	Call::unsigned_extrinsic_with_signed_payload { payload, signature }
+}
+
+fn submit(seed: u8, block_number: u64, price_data: DeliverPrices) -> DispatchResult {
+	match submission(seed, block_number, price_data) {
+		Call::unsigned_extrinsic_with_signed_payload { payload, signature } =>
+			PriceModule::unsigned_extrinsic_with_signed_payload(
+				RuntimeOrigin::none(),
+				payload,
+				signature,
+			),
+		_ => unreachable!(),
+	}
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		PriceModule::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn median_works() {
+	assert_eq!(median(&[]), None);
+	assert_eq!(median(&[5]), Some(5));
+	assert_eq!(median(&[9, 1, 5]), Some(5));
+	// 偶数个价格时取中间两个价格的平均值
+	assert_eq!(median(&[600, 550, 700, 650]), Some(625));
+	assert_eq!(median(&[u32::MAX, u32::MAX]), Some(u32::MAX));
+}
+
+#[test]
+fn it_works_for_submit_prices() {
+	new_test_ext().execute_with(|| {
+		let price_data = prices(&[(b"yuantong", 550), (b"shentong", 600)]);
+		assert_ok!(submit(ALICE, 1, price_data.clone()));
+
+		assert_eq!(SubmittedPrices::<Test>::get(account(ALICE)), Some((1, price_data)));
+		assert_eq!(
+			PriceWindows::<Test>::get(courier(b"yuantong")).into_inner(),
+			vec![(account(ALICE), 550)]
+		);
+		System::assert_last_event(
+			Event::PricesSubmitted { who: account(ALICE), block_number: 1 }.into(),
+		);
+
+		// 中位数在下一个区块开始时计算
+		assert_eq!(PriceModule::median_price(courier(b"yuantong")), None);
+		run_to_block(2);
+		assert_eq!(PriceModule::median_price(courier(b"yuantong")), Some(550));
+		assert_eq!(PriceModule::median_price(courier(b"shentong")), Some(600));
+
+		assert_ok!(submit(BOB, 2, prices(&[(b"yuantong", 650)])));
+		run_to_block(3);
+		assert_eq!(PriceModule::median_price(courier(b"yuantong")), Some(600));
+		assert_eq!(PriceModule::median_price(courier(b"shentong")), Some(600));
+
+		let mut medians = PriceModule::median_prices();
+		medians.sort();
+		assert_eq!(medians, vec![(b"shentong".to_vec(), 600), (b"yuantong".to_vec(), 600)]);
+	});
+}
+
+#[test]
+fn price_window_keeps_latest_price_of_each_authority() {
+	new_test_ext().execute_with(|| {
+		run_to_block(4);
+		assert_ok!(PriceModule::add_authority(RuntimeOrigin::root(), account(CHARLIE)));
+		assert_ok!(submit(BOB, 1, prices(&[(b"yunda", 500)])));
+		assert_ok!(submit(CHARLIE, 1, prices(&[(b"yunda", 520)])));
+
+		// 一个授权账户重复提交离谱的价格，窗口中只保留它最新的价格，不会挤掉其他账户的价格
+		for block_number in 1..=4 {
+			assert_ok!(submit(ALICE, block_number, prices(&[(b"yunda", 10_000)])));
+		}
+		assert_eq!(
+			PriceWindows::<Test>::get(courier(b"yunda")).into_inner(),
+			vec![(account(BOB), 500), (account(CHARLIE), 520), (account(ALICE), 10_000)]
+		);
+		run_to_block(5);
+		assert_eq!(PriceModule::median_price(courier(b"yunda")), Some(520));
+
+		// 移除的授权账户的价格从窗口中删除
+		assert_ok!(PriceModule::remove_authority(RuntimeOrigin::root(), account(BOB)));
+		assert_ok!(PriceModule::add_authority(RuntimeOrigin::root(), account(DAVE)));
+		assert_ok!(submit(DAVE, 5, prices(&[(b"yunda", 510)])));
+		assert_eq!(
+			PriceWindows::<Test>::get(courier(b"yunda")).into_inner(),
+			vec![(account(CHARLIE), 520), (account(ALICE), 10_000), (account(DAVE), 510)]
+		);
+		run_to_block(6);
+		assert_eq!(PriceModule::median_price(courier(b"yunda")), Some(520));
+	});
+}
+
+#[test]
+fn price_window_keeps_only_latest_submission() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit(ALICE, 1, prices(&[(b"yuantong", 550), (b"shentong", 600)])));
+		run_to_block(2);
+		assert_eq!(PriceModule::median_price(courier(b"shentong")), Some(600));
+
+		// 最近一次提交中没有的快递公司，之前的价格也被删除
+		assert_ok!(submit(ALICE, 2, prices(&[(b"yuantong", 500)])));
+		assert!(!PriceWindows::<Test>::contains_key(courier(b"shentong")));
+		run_to_block(3);
+		assert_eq!(PriceModule::median_price(courier(b"yuantong")), Some(500));
+		assert_eq!(PriceModule::median_price(courier(b"shentong")), None);
+	});
+}
+
+#[test]
+fn submit_prices_failed_when_not_authority_or_stale() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			submit(CHARLIE, 1, prices(&[(b"yuantong", 550)])),
+			Error::<Test>::NotAuthority
+		);
+
+		// 不能提交未来区块的价格
+		assert_noop!(submit(ALICE, 2, prices(&[(b"yuantong", 550)])), Error::<Test>::StalePrices);
+
+		// 每个区块只能提交一次
+		assert_ok!(submit(ALICE, 1, prices(&[(b"yuantong", 550)])));
+		assert_noop!(submit(ALICE, 1, prices(&[(b"yuantong", 500)])), Error::<Test>::StalePrices);
+
+		run_to_block(3);
+		assert_ok!(submit(ALICE, 3, prices(&[(b"yuantong", 500)])));
+		assert_noop!(submit(ALICE, 2, prices(&[(b"yuantong", 500)])), Error::<Test>::StalePrices);
+	});
+}
+
+#[test]
+fn submit_prices_failed_when_replayed_or_too_old() {
+	new_test_ext().execute_with(|| {
+		// 移除后重新授权的账户不能重放之前的载荷
+		assert_ok!(submit(BOB, 1, prices(&[(b"yuantong", 550)])));
+		assert_ok!(PriceModule::remove_authority(RuntimeOrigin::root(), account(BOB)));
+		assert_ok!(PriceModule::add_authority(RuntimeOrigin::root(), account(BOB)));
+		assert_noop!(submit(BOB, 1, prices(&[(b"yuantong", 550)])), Error::<Test>::StalePrices);
+
+		// 比当前区块落后超过 MAX_PRICE_AGE 个区块的价格不被接受
+		run_to_block(2 + MAX_PRICE_AGE);
+		assert_noop!(submit(ALICE, 1, prices(&[(b"yuantong", 550)])), Error::<Test>::StalePrices);
+		assert_ok!(submit(ALICE, 2, prices(&[(b"yuantong", 550)])));
+	});
+}
+
+#[test]
+fn validate_unsigned_checks_signature_and_authority() {
+	new_test_ext().execute_with(|| {
+		let validate = |call: &Call<Test>| {
+			<PriceModule as ValidateUnsigned>::validate_unsigned(TransactionSource::External, call)
+		};
+		let invalid = |error| Err(TransactionValidityError::Invalid(error));
+
+		assert!(validate(&submission(ALICE, 1, prices(&[(b"yuantong", 550)]))).is_ok());
+
+		// 签名和载荷的公钥不对应
+		let mut call = submission(ALICE, 1, prices(&[(b"yuantong", 550)]));
+		if let Call::unsigned_extrinsic_with_signed_payload { ref mut signature, .. } = call {
+			*signature = pair(BOB).sign(b"yuantong");
+		}
+		assert_eq!(validate(&call), invalid(InvalidTransaction::BadProof));
+
+		assert_eq!(
+			validate(&submission(CHARLIE, 1, prices(&[(b"yuantong", 550)]))),
+			invalid(InvalidTransaction::BadSigner)
+		);
+
+		assert_ok!(submit(ALICE, 1, prices(&[(b"yuantong", 550)])));
+		assert_eq!(
+			validate(&submission(ALICE, 1, prices(&[(b"yuantong", 550)]))),
+			invalid(InvalidTransaction::Stale)
+		);
+	});
+}
+
+#[test]
+fn it_works_for_manage_authorities() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(PriceModule::authorities().into_inner(), vec![account(ALICE), account(BOB)]);
+
+		assert_noop!(
+			PriceModule::add_authority(RuntimeOrigin::signed(account(ALICE)), account(CHARLIE)),
+			BadOrigin
+		);
+		assert_noop!(
+			PriceModule::add_authority(RuntimeOrigin::root(), account(BOB)),
+			Error::<Test>::AlreadyAuthority
+		);
+		assert_ok!(PriceModule::add_authority(RuntimeOrigin::root(), account(CHARLIE)));
+		System::assert_last_event(Event::AuthorityAdded { who: account(CHARLIE) }.into());
+		assert_noop!(
+			PriceModule::add_authority(RuntimeOrigin::root(), account(DAVE)),
+			Error::<Test>::TooManyAuthorities
+		);
+
+		// 移除授权账户时从价格窗口中删除它最近提交的价格，中位数在下一个区块重新计算
+		assert_ok!(submit(ALICE, 1, prices(&[(b"yuantong", 500)])));
+		assert_ok!(submit(BOB, 1, prices(&[(b"yuantong", 550), (b"shentong", 600)])));
+		run_to_block(2);
+		assert_eq!(PriceModule::median_price(courier(b"yuantong")), Some(525));
+		assert_ok!(PriceModule::remove_authority(RuntimeOrigin::root(), account(BOB)));
+		System::assert_last_event(Event::AuthorityRemoved { who: account(BOB) }.into());
+		assert_eq!(SubmittedPrices::<Test>::get(account(BOB)), None);
+		assert_eq!(PriceModule::last_submission(account(BOB)), Some(1));
+		assert_eq!(
+			PriceWindows::<Test>::get(courier(b"yuantong")).into_inner(),
+			vec![(account(ALICE), 500)]
+		);
+		assert!(!PriceWindows::<Test>::contains_key(courier(b"shentong")));
+		run_to_block(3);
+		assert_eq!(PriceModule::median_price(courier(b"yuantong")), Some(500));
+		assert_eq!(PriceModule::median_price(courier(b"shentong")), None);
+		assert_noop!(
+			PriceModule::remove_authority(RuntimeOrigin::root(), account(BOB)),
+			Error::<Test>::NotAuthority
+		);
+		assert_noop!(submit(BOB, 1, prices(&[(b"yuantong", 550)])), Error::<Test>::NotAuthority);
+
+		assert_ok!(PriceModule::add_authority(RuntimeOrigin::root(), account(DAVE)));
+		assert_ok!(submit(DAVE, 1, prices(&[(b"yuantong", 650)])));
+	});
+}
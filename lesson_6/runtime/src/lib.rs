@@ -13,7 +13,10 @@ mod voter_bags;
 
 use codec::{Decode, Encode};
 
-use frame_support::PalletId;
+use frame_support::{
+	traits::{AsEnsureOriginWithArg, ConstBool, NeverEnsureOrigin},
+	PalletId,
+};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use pallet_election_provider_multi_phase::SolutionAccuracyOf;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
@@ -103,6 +106,9 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Identifier of a kitty.
+pub type KittyId = u32;
+
 impl_opaque_keys! {
 	pub struct SessionKeys {
 		pub babe: Babe,
@@ -146,7 +152,12 @@ pub fn native_version() -> NativeVersion {
 pub struct BaseFilter;
 impl Contains<RuntimeCall> for BaseFilter {
 	fn contains(call: &RuntimeCall) -> bool {
-		!matches!(call, RuntimeCall::TemplateModule(..))
+		match call {
+			RuntimeCall::TemplateModule(..) => false,
+			// kitty 的存储迁移完成之前不接受 kitty 相关的交易
+			RuntimeCall::KittiesModule(..) => !KittiesModule::migration_in_progress(),
+			_ => true,
+		}
 	}
 }
 
@@ -267,7 +278,7 @@ pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -645,10 +656,32 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+	// Three storage items per claim, excluding the claim and metadata bytes charged per byte:
+	// - Proofs: 16-byte key prefix + 1-byte length, and a `ClaimInfo` of 63 bytes
+	//   (owner 32, registered_at 4, expires_at 5, deposit 16, co_owners 1, threshold 4,
+	//   metadata 1) = 80;
+	// - ClaimsByOwner key: 16 + 32 (owner) + 16 + 1 = 65;
+	// - ExpiringClaims key: 8 + 4 (block) + 16 + 1 = 29.
+	pub const ClaimDepositBase: Balance = deposit(3, 174);
+	pub const ClaimDepositPerByte: Balance = deposit(0, 1);
+}
+
 /// Configure the pallet-poe in pallets/poe.
 impl pallet_poe::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxClaimLength = ConstU32<32>;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxBatch = ConstU32<256>;
+	type MaxOwners = ConstU32<16>;
+	type ActionTimeout = ConstU32<{ 7 * DAYS }>;
+	type MaxMimeTypeLength = ConstU32<64>;
+	type MaxDescriptionLength = ConstU32<256>;
+	type OfferTimeout = ConstU32<{ 3 * DAYS }>;
+	// 存证只能通过 offer_claim/accept_claim 转移
+	type TransferOrigin = NeverEnsureOrigin<AccountId>;
 	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
 }
 
@@ -657,20 +690,77 @@ impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 parameter_types! {
 	pub KittyPalletId: PalletId = PalletId(*b"py/kitty");
 	pub KittyPrice: Balance = EXISTENTIAL_DEPOSIT * 10;
+	pub KittyMutationRate: Perbill = Perbill::from_percent(2);
+}
+
+/// Configure the pallet-assets instance holding the shares of fractionalized kitties, the asset
+/// id of the shares is the kitty id.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = KittyId;
+	type AssetIdParameter = codec::Compact<KittyId>;
+	type Currency = Balances;
+	// 份额资产只能由 kitties pallet 创建
+	type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<DOLLARS>;
+	type AssetAccountDeposit = ConstU128<DOLLARS>;
+	type MetadataDepositBase = ConstU128<DOLLARS>;
+	type MetadataDepositPerByte = ConstU128<CENTS>;
+	type ApprovalDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub KittyBuyoutThreshold: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_kitties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type Randomness = RandomnessModule;
+	type KittyId = KittyId;
+	// 优先使用上一个区块的 BABE VRF 输出，没有时使用上一个 epoch 的随机数
+	type Randomness = pallet_kitties::randomness::RandomnessOrElse<
+		pallet_babe::ParentBlockRandomness<Runtime>,
+		pallet_babe::RandomnessFromOneEpochAgo<Runtime>,
+	>;
 	type Currency = Balances;
 	type KittyPrice = KittyPrice;
 	type PalletId = KittyPalletId;
+	type MaxAuctionDuration = ConstU32<{ 30 * DAYS }>;
+	type MaxAuctionsPerBlock = ConstU32<64>;
+	type MaxLoanDuration = ConstU32<{ 30 * DAYS }>;
+	type MaxLoansPerBlock = ConstU32<64>;
+	type MaxKittiesOwned = ConstU32<256>;
+	type MutationRate = KittyMutationRate;
+	type BreedCooldownBase = ConstU32<HOURS>;
+	type MaxBreedCooldown = ConstU32<{ 7 * DAYS }>;
+	type BreedRevealWindow = ConstU32<HOURS>;
+	type UniqueNames = ConstBool<true>;
+	type MigrationBatchSize = ConstU32<1000>;
+	type MaxRecycledKittyIds = ConstU32<1024>;
+	type ShareAssets = KittyShares;
+	type BuyoutThreshold = KittyBuyoutThreshold;
+	type BuyoutOfferDuration = ConstU32<{ 7 * DAYS }>;
+	type MaxBuyoutVoters = ConstU32<64>;
+	type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure the pallet-price in pallets/template.
 impl pallet_price::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AuthorityId = pallet_price::crypto::TestAuthId;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MaxAuthorities = ConstU32<32>;
+	type WindowLength = ConstU32<64>;
+	type MaxPriceAge = ConstU32<10>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -706,6 +796,7 @@ construct_runtime!(
 		PoeModule: pallet_poe,
 		RandomnessModule: pallet_insecure_randomness_collective_flip,
 		KittiesModule: pallet_kitties,
+		KittyShares: pallet_assets,
 		PriceModule: pallet_price,
 	}
 );
@@ -740,8 +831,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations run on runtime upgrade, before the pallets' own hooks.
+pub type Migrations = (pallet_kitties::migrations::Migrations<Runtime>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -758,6 +853,7 @@ mod benches {
 		[pallet_im_online, ImOnline]
 		[pallet_template, TemplateModule]
 		[pallet_poe, PoeModule]
+		[pallet_kitties, KittiesModule]
 	);
 }
 
@@ -960,6 +1056,36 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, Hash> for Runtime {
+		fn claims_of(owner: AccountId) -> Vec<Vec<u8>> {
+			PoeModule::claims_of(owner).into_iter().map(|claim| claim.into_inner()).collect()
+		}
+
+		fn verify_inclusion(root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool {
+			PoeModule::verify_inclusion(root, leaf, proof)
+		}
+	}
+
+	impl pallet_price_runtime_api::PriceApi<Block> for Runtime {
+		fn median_price(kuaidi_com: Vec<u8>) -> Option<u32> {
+			pallet_price::KuaidiCom::try_from(kuaidi_com).ok().and_then(PriceModule::median_price)
+		}
+
+		fn median_prices() -> Vec<(Vec<u8>, u32)> {
+			PriceModule::median_prices()
+		}
+	}
+
+	impl pallet_kitties_runtime_api::KittiesApi<Block, KittyId> for Runtime {
+		fn traits_of(kitty_id: KittyId) -> Option<pallet_kitties::genome::Genome> {
+			KittiesModule::traits_of(kitty_id)
+		}
+
+		fn name_of(kitty_id: KittyId) -> Option<scale_info::prelude::string::String> {
+			KittiesModule::name_of(kitty_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (